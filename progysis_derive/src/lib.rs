@@ -0,0 +1,231 @@
+//!
+//! Derive macros for the lattice traits of `progysis`.
+//!
+//! Should be used through the re-exports in `progysis::core`, which require the `derive`
+//! feature of `progysis`.
+//!
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+	parse_macro_input, parse_quote, Data, DeriveInput, Fields, Generics, Index, Member, Type,
+	WherePredicate,
+};
+
+///
+/// Derives `CompleteLattice` for a struct of lattices as their pointwise product.
+///
+/// Also derives the operators required by `CompleteLattice`:
+///
+/// * `PartialOrd`: `s1 <= s2` iff `s1.f <= s2.f` for all fields `f`.
+/// * `Add` and `AddAssign` (both by value and by reference): Fieldwise join.
+///
+/// `Clone` and `PartialEq` must be derived separately.
+///
+#[proc_macro_derive(CompleteLattice)]
+pub fn derive_complete_lattice(input: TokenStream) -> TokenStream
+{
+	let input = parse_macro_input!(input as DeriveInput);
+	let (members, types) = match struct_fields(&input) {
+		Ok(fields) => fields,
+		Err(err) => return err.to_compile_error().into(),
+	};
+	let name = &input.ident;
+	let generics = bound_fields(&input.generics, &types,
+		quote!(::progysis::core::CompleteLattice));
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	
+	let mut ref_generics = generics.clone();
+	ref_generics.params.insert(0, parse_quote!('__progysis_a));
+	let (ref_impl_generics, _, _) = ref_generics.split_for_impl();
+	
+	let expanded = quote!{
+		impl #impl_generics ::progysis::core::CompleteLattice for #name #ty_generics #where_clause
+		{
+			fn is_bottom(&self) -> bool
+			{
+				true #(&& <#types as ::progysis::core::CompleteLattice>::is_bottom(&self.#members))*
+			}
+			
+			fn join_assign(&mut self, other: &Self) -> bool
+			{
+				false #(| <#types as ::progysis::core::CompleteLattice>::join_assign(
+					&mut self.#members, &other.#members))*
+			}
+		}
+		
+		impl #impl_generics ::std::cmp::PartialOrd for #name #ty_generics #where_clause
+		{
+			fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering>
+			{
+				let le = true #(&& self.#members <= other.#members)*;
+				let ge = true #(&& self.#members >= other.#members)*;
+				match (le, ge) {
+					(true, true) => Some(::std::cmp::Ordering::Equal),
+					(true, false) => Some(::std::cmp::Ordering::Less),
+					(false, true) => Some(::std::cmp::Ordering::Greater),
+					(false, false) => None,
+				}
+			}
+		}
+		
+		impl #impl_generics ::std::ops::Add for #name #ty_generics #where_clause
+		{
+			type Output = Self;
+			fn add(mut self, rhs: Self) -> Self::Output
+			{
+				#(self.#members += rhs.#members;)*
+				self
+			}
+		}
+		
+		impl #ref_impl_generics ::std::ops::Add<&'__progysis_a Self> for #name #ty_generics
+			#where_clause
+		{
+			type Output = Self;
+			fn add(mut self, rhs: &'__progysis_a Self) -> Self::Output
+			{
+				#(self.#members += &rhs.#members;)*
+				self
+			}
+		}
+		
+		impl #impl_generics ::std::ops::AddAssign for #name #ty_generics #where_clause
+		{
+			fn add_assign(&mut self, rhs: Self)
+			{
+				#(self.#members += rhs.#members;)*
+			}
+		}
+		
+		impl #ref_impl_generics ::std::ops::AddAssign<&'__progysis_a Self> for #name #ty_generics
+			#where_clause
+		{
+			fn add_assign(&mut self, rhs: &'__progysis_a Self)
+			{
+				#(self.#members += &rhs.#members;)*
+			}
+		}
+	};
+	expanded.into()
+}
+
+///
+/// Derives `Bottom` for a struct of lattices, where every field is bottom.
+///
+#[proc_macro_derive(Bottom)]
+pub fn derive_bottom(input: TokenStream) -> TokenStream
+{
+	let input = parse_macro_input!(input as DeriveInput);
+	let (members, types) = match struct_fields(&input) {
+		Ok(fields) => fields,
+		Err(err) => return err.to_compile_error().into(),
+	};
+	let name = &input.ident;
+	let generics = bound_fields(&input.generics, &types, quote!(::progysis::core::Bottom));
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	
+	let expanded = quote!{
+		impl #impl_generics ::progysis::core::Bottom for #name #ty_generics #where_clause
+		{
+			fn bottom() -> Self
+			{
+				Self{
+					#(#members: <#types as ::progysis::core::Bottom>::bottom(),)*
+				}
+			}
+		}
+	};
+	expanded.into()
+}
+
+///
+/// Derives `SubLattice<T>` for each field annotated with `#[sub_lattice]`,
+/// where `T` is the type of the field.
+///
+/// No two annotated fields may have the same type.
+///
+#[proc_macro_derive(SubLattice, attributes(sub_lattice))]
+pub fn derive_sub_lattice(input: TokenStream) -> TokenStream
+{
+	let input = parse_macro_input!(input as DeriveInput);
+	let (members, types) = match struct_fields(&input) {
+		Ok(fields) => fields,
+		Err(err) => return err.to_compile_error().into(),
+	};
+	let fields = match &input.data {
+		Data::Struct(data) => &data.fields,
+		_ => unreachable!("Checked by 'struct_fields'"),
+	};
+	let name = &input.ident;
+	
+	let mut expanded = TokenStream2::new();
+	for ((field, member), ty) in fields.iter().zip(members.iter()).zip(types.iter()) {
+		if !field.attrs.iter().any(|attr| attr.path().is_ident("sub_lattice")) {
+			continue;
+		}
+		let generics = bound_fields(&input.generics, std::slice::from_ref(ty),
+			quote!(::progysis::core::CompleteLattice));
+		let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+		expanded.extend(quote!{
+			impl #impl_generics ::progysis::core::SubLattice<#ty> for #name #ty_generics
+				#where_clause
+			{
+				fn sub_lattice(self) -> #ty
+				{
+					self.#member
+				}
+				
+				fn sub_lattice_ref(&self) -> &#ty
+				{
+					&self.#member
+				}
+				
+				fn sub_lattice_ref_mut(&mut self) -> &mut #ty
+				{
+					&mut self.#member
+				}
+			}
+		});
+	}
+	expanded.into()
+}
+
+// Helper functions
+
+/// The members (names or indices) and types of the fields of the given struct.
+fn struct_fields(input: &DeriveInput) -> Result<(Vec<Member>, Vec<Type>), syn::Error>
+{
+	let fields = match &input.data {
+		Data::Struct(data) => &data.fields,
+		_ => return Err(syn::Error::new_spanned(&input.ident,
+			"lattices can only be derived for structs")),
+	};
+	if let Fields::Unit = fields {
+		return Err(syn::Error::new_spanned(&input.ident,
+			"lattices can't be derived for unit structs"));
+	}
+	let members = fields.iter().enumerate().map(|(i, f)|
+		match &f.ident {
+			Some(ident) => Member::Named(ident.clone()),
+			None => Member::Unnamed(Index::from(i)),
+		}
+	).collect();
+	let types = fields.iter().map(|f| f.ty.clone()).collect();
+	Ok((members, types))
+}
+
+/// Adds the given bound to the where clause for each of the given types.
+fn bound_fields(generics: &Generics, types: &[Type], bound: TokenStream2) -> Generics
+{
+	let mut generics = generics.clone();
+	let where_clause = generics.make_where_clause();
+	for ty in types {
+		let predicate: WherePredicate = parse_quote!(#ty: #bound);
+		where_clause.predicates.push(predicate);
+	}
+	generics
+}
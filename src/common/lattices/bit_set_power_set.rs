@@ -0,0 +1,384 @@
+
+use std::{
+	borrow::Cow,
+	cmp::{
+		Ordering, min
+	},
+	fmt::{
+		Debug, Formatter, Result as FmtResult
+	},
+	marker::PhantomData,
+	ops::{
+		Add, AddAssign
+	}
+};
+use crate::core::{
+	CompleteLattice, PowerSet, PowerSetItem, Bottom, Top, Finite, Meet
+};
+
+const WORD_BITS: usize = 64;
+
+///
+/// An item of a [`BitSetPowerSet`].
+///
+/// Each item must map to a unique index, such that `from_index(i.to_index()) == i`.
+/// Since the power set stores one bit for every index up to the largest it contains,
+/// indices should be dense and start from 0.
+///
+/// [`BitSetPowerSet`]: struct.BitSetPowerSet.html
+///
+pub trait BitSetPowerSetItem: PowerSetItem
+{
+	fn to_index(&self) -> usize;
+	
+	fn from_index(index: usize) -> Self;
+}
+
+///
+/// A [Power Set] stored as a dense bit vector, with a bit for each index of the
+/// items it is over.
+///
+/// Joining, meeting and comparing are done a word at a time, making it much faster than
+/// [`HashPowerSet`] for gen/kill analyses over many items.
+///
+/// [Power Set]: http://mathworld.wolfram.com/PowerSet.html
+/// [`HashPowerSet`]: struct.HashPowerSet.html
+///
+#[derive(Clone)]
+pub struct BitSetPowerSet<E>
+	where
+		E: BitSetPowerSetItem
+{
+	/// Invariant: The last word is never 0.
+	words: Vec<u64>,
+	item: PhantomData<E>,
+}
+
+impl<E> BitSetPowerSet<E>
+	where
+		E: BitSetPowerSetItem
+{
+	fn insert_index(&mut self, index: usize)
+	{
+		let word = index / WORD_BITS;
+		if word >= self.words.len() {
+			self.words.resize(word + 1, 0);
+		}
+		self.words[word] |= 1 << (index % WORD_BITS);
+	}
+	
+	fn contains_index(&self, index: usize) -> bool
+	{
+		self.words.get(index / WORD_BITS)
+			.map(|w| w & (1 << (index % WORD_BITS)) != 0)
+			.unwrap_or(false)
+	}
+	
+	/// Reestablishes the invariant.
+	fn trim(&mut self)
+	{
+		while self.words.last() == Some(&0) {
+			self.words.pop();
+		}
+	}
+	
+	fn indices<'a>(&'a self) -> impl Iterator<Item=usize> + 'a
+	{
+		self.words.iter().enumerate().flat_map(|(i, &w)|
+			(0..WORD_BITS).filter(move |b| w & (1 << b) != 0).map(move |b| i * WORD_BITS + b)
+		)
+	}
+}
+
+impl<E> PowerSet for BitSetPowerSet<E>
+	where
+		E: BitSetPowerSetItem
+{
+	type Item = E;
+	type All = Vec<E>;
+	type Iter<'a> = BitSetPowerSetIter<'a, E> where Self: 'a;
+	
+	fn singleton(s: Self::Item) -> Self
+	{
+		let mut result = Self::bottom();
+		result.insert_index(s.to_index());
+		result
+	}
+	
+	fn all(&self) -> Self::All
+	{
+		self.indices().map(E::from_index).collect()
+	}
+	
+	fn iter(&self) -> Self::Iter<'_>
+	{
+		BitSetPowerSetIter{words: &self.words, index: 0, item: PhantomData}
+	}
+	
+	fn contains(&self, item: &E) -> bool
+	{
+		self.contains_index(item.to_index())
+	}
+	
+	fn len(&self) -> usize
+	{
+		self.words.iter().map(|w| w.count_ones() as usize).sum()
+	}
+	
+	fn insert(&mut self, item: E) -> bool
+	{
+		let index = item.to_index();
+		let absent = !self.contains_index(index);
+		self.insert_index(index);
+		absent
+	}
+	
+	fn remove(&mut self, item: &E) -> bool
+	{
+		let index = item.to_index();
+		let present = self.contains_index(index);
+		if present {
+			self.words[index / WORD_BITS] &= !(1 << (index % WORD_BITS));
+			self.trim();
+		}
+		present
+	}
+	
+	fn from_iter<F>(i: F) -> Self
+		where F: IntoIterator<Item=Self::Item>
+	{
+		let mut result = Self::bottom();
+		for e in i.into_iter() {
+			result.insert_index(e.to_index());
+		}
+		result
+	}
+}
+
+impl<E> PartialOrd for BitSetPowerSet<E>
+	where
+		E: BitSetPowerSetItem
+{
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		let self_subset = word_subset(&self.words, &other.words);
+		let other_subset = word_subset(&other.words, &self.words);
+		match (self_subset, other_subset) {
+			(true, true) => Some(Ordering::Equal),
+			(true, false) => Some(Ordering::Less),
+			(false, true) => Some(Ordering::Greater),
+			(false, false) => None,
+		}
+	}
+}
+
+impl<E> PartialEq for BitSetPowerSet<E>
+	where
+		E: BitSetPowerSetItem
+{
+	fn eq(&self, other: &Self) -> bool
+	{
+		self.words == other.words
+	}
+}
+
+impl<'a,E> Add<&'a Self> for BitSetPowerSet<E>
+	where
+		E: BitSetPowerSetItem
+{
+	type Output = Self;
+	
+	fn add(mut self, other: &'a Self) -> Self::Output
+	{
+		join(&mut self, other);
+		self
+	}
+}
+
+impl<E> Add<Self> for BitSetPowerSet<E>
+	where
+		E: BitSetPowerSetItem
+{
+	type Output = Self;
+	
+	fn add(mut self, other: Self) -> Self::Output
+	{
+		join(&mut self, &other);
+		self
+	}
+}
+
+impl<E> AddAssign for BitSetPowerSet<E>
+	where
+		E: BitSetPowerSetItem
+{
+	fn add_assign(&mut self, rhs: Self)
+	{
+		join(self, &rhs);
+	}
+}
+
+impl<'a,E> AddAssign<&'a Self> for BitSetPowerSet<E>
+	where
+		E: BitSetPowerSetItem
+{
+	fn add_assign(&mut self, rhs: &'a Self)
+	{
+		join(self, rhs);
+	}
+}
+
+impl<E> CompleteLattice for BitSetPowerSet<E>
+	where
+		E: BitSetPowerSetItem
+{
+	fn is_bottom(&self) -> bool
+	{
+		self.words.is_empty()
+	}
+	
+	fn join_assign(&mut self, other: &Self) -> bool
+	{
+		let changed = !word_subset(&other.words, &self.words);
+		if changed {
+			join(self, other);
+		}
+		changed
+	}
+}
+
+impl<E> Bottom for BitSetPowerSet<E>
+	where
+		E: BitSetPowerSetItem
+{
+	fn bottom() -> Self
+	{
+		Self{words: Vec::new(), item: PhantomData}
+	}
+}
+
+impl<E> Top for BitSetPowerSet<E>
+	where
+		E: BitSetPowerSetItem + Finite
+{
+	fn top() -> Self
+	{
+		Self::from_iter(E::universe())
+	}
+	
+	fn is_top(&self) -> bool
+	{
+		E::universe().iter().all(|e| self.contains_index(e.to_index()))
+	}
+}
+
+impl<E> Meet for BitSetPowerSet<E>
+	where
+		E: BitSetPowerSetItem
+{
+	fn meet_assign(&mut self, other: &Self)
+	{
+		self.words.truncate(min(self.words.len(), other.words.len()));
+		for (w, o) in self.words.iter_mut().zip(other.words.iter()) {
+			*w &= *o;
+		}
+		self.trim();
+	}
+}
+
+impl<E> Debug for BitSetPowerSet<E>
+	where
+		E: BitSetPowerSetItem + Debug
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		f.debug_set().entries(self.indices().map(E::from_index)).finish()
+	}
+}
+
+///
+/// Iterator over the items of a [`BitSetPowerSet`].
+///
+/// Since the items aren't stored, they are created from their indices as they are visited.
+///
+/// [`BitSetPowerSet`]: struct.BitSetPowerSet.html
+///
+pub struct BitSetPowerSetIter<'a, E>
+	where
+		E: BitSetPowerSetItem
+{
+	words: &'a [u64],
+	/// The next index to check.
+	index: usize,
+	item: PhantomData<E>,
+}
+
+impl<'a, E> Iterator for BitSetPowerSetIter<'a, E>
+	where
+		E: BitSetPowerSetItem + 'a
+{
+	type Item = Cow<'a, E>;
+	
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		while let Some(&w) = self.words.get(self.index / WORD_BITS) {
+			// Skip the bits of the current word that have already been visited
+			let remaining = w >> (self.index % WORD_BITS);
+			if remaining == 0 {
+				self.index = (self.index / WORD_BITS + 1) * WORD_BITS;
+			} else {
+				let found = self.index + remaining.trailing_zeros() as usize;
+				self.index = found + 1;
+				return Some(Cow::Owned(E::from_index(found)));
+			}
+		}
+		None
+	}
+}
+
+impl BitSetPowerSetItem for usize
+{
+	fn to_index(&self) -> usize
+	{
+		*self
+	}
+	
+	fn from_index(index: usize) -> Self
+	{
+		index
+	}
+}
+
+impl BitSetPowerSetItem for u32
+{
+	fn to_index(&self) -> usize
+	{
+		*self as usize
+	}
+	
+	fn from_index(index: usize) -> Self
+	{
+		index as u32
+	}
+}
+
+// Helper functions
+
+fn join<E>(left: &mut BitSetPowerSet<E>, right: &BitSetPowerSet<E>)
+	where
+		E: BitSetPowerSetItem
+{
+	if left.words.len() < right.words.len() {
+		left.words.resize(right.words.len(), 0);
+	}
+	for (w, r) in left.words.iter_mut().zip(right.words.iter()) {
+		*w |= *r;
+	}
+}
+
+/// Whether all the bits set in `subset` are also set in `superset`.
+fn word_subset(subset: &[u64], superset: &[u64]) -> bool
+{
+	subset.len() <= superset.len()
+		&& subset.iter().zip(superset.iter()).all(|(s, o)| s & !o == 0)
+}
@@ -0,0 +1,25 @@
+
+
+use std::collections::BTreeSet;
+use crate::core::PowerSetItem;
+
+trait_alias!(BTreePowerSetItem: PowerSetItem, Ord);
+
+///
+/// A [Power Set] over ordered items.
+///
+/// Behaves like [`HashPowerSet`], except that its items are always iterated,
+/// returned and formatted in order, making results deterministic.
+///
+/// [Power Set]: http://mathworld.wolfram.com/PowerSet.html
+/// [`HashPowerSet`]: struct.HashPowerSet.html
+///
+#[derive(Debug, Clone)]
+pub struct BTreePowerSet<E>
+	where
+		E: BTreePowerSetItem
+{
+	set: BTreeSet<E>
+}
+
+set_power_set!(BTreePowerSet, BTreeSet, BTreePowerSetItem);
@@ -0,0 +1,29 @@
+
+use std::collections::{
+	BTreeMap, BTreeSet
+};
+use crate::core::{
+	TFSpaceKey, TFSpaceElement
+};
+
+trait_alias!(BTreeTFSpaceKey: TFSpaceKey, Ord);
+trait_alias!(BTreeTFSpaceElement: TFSpaceElement);
+
+///
+/// A Total Function Space backed by a `BTreeMap`.
+///
+/// Behaves like [`HashTFSpace`], except that its keys are always returned in
+/// ascending order, making iteration and `Debug` output deterministic.
+///
+/// [`HashTFSpace`]: struct.HashTFSpace.html
+///
+#[derive(Clone, Debug)]
+pub struct BTreeTFSpace<K,E>
+	where
+		K: BTreeTFSpaceKey,
+		E: BTreeTFSpaceElement
+{
+	map: BTreeMap<K,E>,
+}
+
+map_tf_space!(BTreeTFSpace, BTreeMap, BTreeSet, BTreeTFSpaceKey, BTreeTFSpaceElement);
@@ -0,0 +1,191 @@
+
+use std::{
+	cmp::Ordering,
+	ops::{
+		Add, AddAssign
+	}
+};
+use crate::core::{
+	CompleteLattice, Bottom, Top, Meet, Abstraction
+};
+
+trait_alias!(FlatValue: Clone, Eq);
+
+///
+/// A flat [Complete Lattice] over a set of values.
+///
+/// All values are mutually incomparable, and lie between a dedicated bottom and top element.
+/// Joining two different values therefore results in top.
+///
+/// Typically used for constant propagation, where `Value(v)` means the constant `v`,
+/// and `Top` means the value is not constant.
+///
+/// [Complete Lattice]: http://mathworld.wolfram.com/CompleteLattice.html
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Flat<T>
+	where
+		T: FlatValue
+{
+	Bottom,
+	Value(T),
+	Top,
+}
+
+impl<T> Flat<T>
+	where
+		T: FlatValue
+{
+	///
+	/// Returns the value of this element, if it isn't bottom or top.
+	///
+	pub fn value(&self) -> Option<&T>
+	{
+		match self {
+			Flat::Value(v) => Some(v),
+			_ => None,
+		}
+	}
+}
+
+impl<T> CompleteLattice for Flat<T>
+	where
+		T: FlatValue
+{
+	fn is_bottom(&self) -> bool
+	{
+		*self == Flat::Bottom
+	}
+}
+
+impl<T> Bottom for Flat<T>
+	where
+		T: FlatValue
+{
+	fn bottom() -> Self
+	{
+		Flat::Bottom
+	}
+}
+
+impl<T> Top for Flat<T>
+	where
+		T: FlatValue
+{
+	fn top() -> Self
+	{
+		Flat::Top
+	}
+	
+	fn is_top(&self) -> bool
+	{
+		*self == Flat::Top
+	}
+}
+
+impl<T> Abstraction<T> for Flat<T>
+	where
+		T: FlatValue
+{
+	fn alpha(concrete: &T) -> Self
+	{
+		Flat::Value(concrete.clone())
+	}
+	
+	fn gamma_contains(&self, concrete: &T) -> bool
+	{
+		match self {
+			Flat::Bottom => false,
+			Flat::Value(v) => v == concrete,
+			Flat::Top => true,
+		}
+	}
+}
+
+impl<T> Meet for Flat<T>
+	where
+		T: FlatValue
+{
+	fn meet_assign(&mut self, other: &Self)
+	{
+		match (&*self, other) {
+			(Flat::Bottom, _) | (_, Flat::Top) => (),
+			(Flat::Top, _) => *self = other.clone(),
+			(Flat::Value(v1), Flat::Value(v2)) if v1 == v2 => (),
+			_ => *self = Flat::Bottom,
+		}
+	}
+}
+
+impl<T> PartialOrd for Flat<T>
+	where
+		T: FlatValue
+{
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		match (self, other) {
+			_ if self == other => Some(Ordering::Equal),
+			(Flat::Bottom, _) | (_, Flat::Top) => Some(Ordering::Less),
+			(Flat::Top, _) | (_, Flat::Bottom) => Some(Ordering::Greater),
+			_ => None,
+		}
+	}
+}
+
+impl<T> Add for Flat<T>
+	where
+		T: FlatValue
+{
+	type Output = Self;
+	fn add(mut self, rhs: Self) -> Self::Output
+	{
+		join(&mut self, &rhs);
+		self
+	}
+}
+
+impl<'a, T> Add<&'a Self> for Flat<T>
+	where
+		T: FlatValue
+{
+	type Output = Self;
+	fn add(mut self, rhs: &'a Self) -> Self::Output
+	{
+		join(&mut self, rhs);
+		self
+	}
+}
+
+impl<T> AddAssign for Flat<T>
+	where
+		T: FlatValue
+{
+	fn add_assign(&mut self, rhs: Self)
+	{
+		join(self, &rhs);
+	}
+}
+
+impl<'a, T> AddAssign<&'a Self> for Flat<T>
+	where
+		T: FlatValue
+{
+	fn add_assign(&mut self, rhs: &'a Self)
+	{
+		join(self, rhs);
+	}
+}
+
+// Helper functions
+
+fn join<T>(left: &mut Flat<T>, right: &Flat<T>)
+	where
+		T: FlatValue
+{
+	match (&*left, right) {
+		(Flat::Top, _) | (_, Flat::Bottom) => (),
+		(Flat::Bottom, _) => *left = right.clone(),
+		(Flat::Value(v1), Flat::Value(v2)) if v1 == v2 => (),
+		_ => *left = Flat::Top,
+	}
+}
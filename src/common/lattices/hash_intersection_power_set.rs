@@ -0,0 +1,249 @@
+
+use std::{
+	borrow::Cow,
+	convert::From,
+	cmp::Ordering,
+	collections::HashSet,
+	collections::hash_set::Iter,
+	iter::Map,
+	ops::{
+		Add,AddAssign
+	}
+};
+use crate::core::{
+	CompleteLattice, PowerSet, Bottom, Top, Finite, Meet
+};
+use super::HashPowerSetItem;
+
+///
+/// A [Power Set] ordered by reverse inclusion.
+///
+/// Where [`HashPowerSet`] joins using union and has the empty set as bottom,
+/// this joins using intersection and has the set of all items as bottom,
+/// making it suitable for must-analyses.
+/// Since the bottom element must be representable, the items must be [`Finite`].
+///
+/// [Power Set]: http://mathworld.wolfram.com/PowerSet.html
+/// [`HashPowerSet`]: struct.HashPowerSet.html
+/// [`Finite`]: ../../core/trait.Finite.html
+///
+#[derive(Debug, Clone)]
+pub struct HashIntersectionPowerSet<E>
+	where
+		E: HashPowerSetItem + Finite
+{
+	set: HashSet<E>
+}
+
+impl<E> PowerSet for HashIntersectionPowerSet<E>
+	where
+		E: HashPowerSetItem + Finite
+{
+	type Item = E;
+	type All = HashSet<E>;
+	type Iter<'a> = Map<Iter<'a, E>, fn(&'a E) -> Cow<'a, E>> where Self: 'a;
+	
+	fn singleton(s: Self::Item) -> Self
+	{
+		let mut set = HashSet::new();
+		set.insert(s);
+		Self{set}
+	}
+	
+	fn all(&self) -> Self::All{
+		self.set.clone()
+	}
+	
+	fn iter<'a>(&'a self) -> Self::Iter<'a>
+	{
+		self.set.iter().map(Cow::Borrowed as fn(&'a E) -> _)
+	}
+	
+	fn contains(&self, item: &E) -> bool
+	{
+		self.set.contains(item)
+	}
+	
+	fn len(&self) -> usize
+	{
+		self.set.len()
+	}
+	
+	fn insert(&mut self, item: E) -> bool
+	{
+		self.set.insert(item)
+	}
+	
+	fn remove(&mut self, item: &E) -> bool
+	{
+		self.set.remove(item)
+	}
+	
+	fn from_iter<F>(i: F) -> Self
+		where F: IntoIterator<Item=Self::Item>
+	{
+		// Joining singletons would intersect them, so the set is built directly
+		Self{set: i.into_iter().collect()}
+	}
+}
+
+impl<E> PartialOrd for HashIntersectionPowerSet<E>
+	where
+		E: HashPowerSetItem + Finite
+{
+	fn partial_cmp(&self, other:&Self) -> Option<Ordering>
+	{
+		let self_superset = self.set.is_superset(&other.set);
+		let other_superset = other.set.is_superset(&self.set);
+		if self_superset {
+			if other_superset {
+				Some(Ordering::Equal)
+			} else {
+				Some(Ordering::Less)
+			}
+		} else if other_superset {
+			Some(Ordering::Greater)
+		} else {
+			None
+		}
+	}
+}
+
+impl<E> PartialEq for HashIntersectionPowerSet<E>
+	where
+		E: HashPowerSetItem + Finite
+{
+	fn eq(&self, other:&Self) -> bool
+	{
+		self.set == other.set
+	}
+}
+
+impl<'a,E> Add<&'a Self> for HashIntersectionPowerSet<E>
+	where
+		E: HashPowerSetItem + Finite
+{
+	type Output = Self;
+	
+	fn add(mut self, other: &'a Self) -> Self::Output
+	{
+		join(&mut self, other);
+		self
+	}
+}
+
+impl<E> Add<Self> for HashIntersectionPowerSet<E>
+	where
+		E: HashPowerSetItem + Finite
+{
+	type Output = Self;
+	
+	fn add(mut self, other: Self) -> Self::Output
+	{
+		join(&mut self, &other);
+		self
+	}
+}
+
+impl<E> AddAssign for HashIntersectionPowerSet<E>
+	where
+		E: HashPowerSetItem + Finite
+{
+	fn add_assign(&mut self, rhs: Self)
+	{
+		join(self, &rhs);
+	}
+}
+
+impl<'a,E> AddAssign<&'a Self> for HashIntersectionPowerSet<E>
+	where
+		E: HashPowerSetItem + Finite
+{
+	fn add_assign(&mut self, rhs: &'a Self)
+	{
+		join(self, rhs);
+	}
+}
+
+impl<E> CompleteLattice for HashIntersectionPowerSet<E>
+	where
+		E: HashPowerSetItem + Finite
+{
+	fn is_bottom(&self) -> bool
+	{
+		E::universe().iter().all(|e| self.set.contains(e))
+	}
+	
+	fn join_assign(&mut self, other: &Self) -> bool
+	{
+		let len = self.set.len();
+		join(self, other);
+		len != self.set.len()
+	}
+}
+
+impl<E> Bottom for HashIntersectionPowerSet<E>
+	where
+		E: HashPowerSetItem + Finite
+{
+	fn bottom() -> Self
+	{
+		Self{set: E::universe().into_iter().collect()}
+	}
+}
+
+impl<E> Top for HashIntersectionPowerSet<E>
+	where
+		E: HashPowerSetItem + Finite
+{
+	fn top() -> Self
+	{
+		Self{set: HashSet::new()}
+	}
+	
+	fn is_top(&self) -> bool
+	{
+		self.set.is_empty()
+	}
+}
+
+impl<E> Meet for HashIntersectionPowerSet<E>
+	where
+		E: HashPowerSetItem + Finite
+{
+	fn meet_assign(&mut self, other: &Self)
+	{
+		for e in other.set.iter(){
+			if !self.set.contains(e) {
+				self.set.insert(e.clone());
+			}
+		}
+	}
+}
+
+impl<E> From<HashSet<E>> for HashIntersectionPowerSet<E>
+	where E: HashPowerSetItem + Finite
+{
+	fn from(set: HashSet<E>) -> Self
+	{
+		Self{set}
+	}
+}
+
+impl<E> From<HashIntersectionPowerSet<E>> for HashSet<E>
+	where E: HashPowerSetItem + Finite
+{
+	fn from(set: HashIntersectionPowerSet<E>) -> Self
+	{
+		set.set
+	}
+}
+
+// Helper functions
+
+fn join<E>(left: &mut HashIntersectionPowerSet<E>, right: &HashIntersectionPowerSet<E>)
+	where
+		E: HashPowerSetItem + Finite
+{
+	left.set.retain(|e| right.set.contains(e));
+}
@@ -0,0 +1,581 @@
+
+use std::{
+	cmp::{
+		Ordering, min, max
+	},
+	fmt::{
+		Debug, Formatter, Result as FmtResult
+	},
+	ops::{
+		Add, AddAssign
+	}
+};
+use crate::core::{
+	CompleteLattice, Bottom, Top, Meet, Widen, Narrow, Abstraction
+};
+use super::Flat;
+
+///
+/// A bound of an [`Interval`].
+///
+/// [`Interval`]: struct.Interval.html
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IntervalBound
+{
+	NegInf,
+	Int(i64),
+	PosInf,
+}
+
+///
+/// The [Interval] abstract domain over signed integers.
+///
+/// Each element is either empty (bottom) or the set of integers between a lower and upper
+/// bound (inclusive), where the bounds may be infinite.
+/// Elements are ordered by inclusion, such that joining gives the smallest interval
+/// containing both.
+///
+/// Since `+` is the join, the abstract arithmetic is provided by the methods
+/// [`plus`], [`minus`], [`times`], [`divide`] and [`negate`].
+/// Results that exceed the range of `i64` are approximated soundly.
+///
+/// The lattice has infinite ascending chains, so analyses over it should use
+/// [`analyze_widening`].
+///
+/// [Interval]: https://en.wikipedia.org/wiki/Interval_arithmetic
+/// [`plus`]: #method.plus
+/// [`minus`]: #method.minus
+/// [`times`]: #method.times
+/// [`divide`]: #method.divide
+/// [`negate`]: #method.negate
+/// [`analyze_widening`]: ../../core/trait.Analysis.html#method.analyze_widening
+///
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Interval
+{
+	/// Invariant: If present, the lower bound isn't `PosInf`, the upper bound isn't `NegInf`
+	/// and the lower bound isn't larger than the upper bound.
+	bounds: Option<(IntervalBound, IntervalBound)>
+}
+
+impl Interval
+{
+	///
+	/// The interval of all integers between `lower` and `upper` (inclusive).
+	///
+	/// Is empty if `lower > upper`.
+	///
+	pub fn new(lower: i64, upper: i64) -> Self
+	{
+		Self::from_bounds(IntervalBound::Int(lower), IntervalBound::Int(upper))
+	}
+	
+	///
+	/// The interval of all integers between the given bounds (inclusive).
+	///
+	/// Is empty if no integer lies between the bounds.
+	///
+	pub fn from_bounds(lower: IntervalBound, upper: IntervalBound) -> Self
+	{
+		if lower > upper || lower == IntervalBound::PosInf || upper == IntervalBound::NegInf {
+			Self::bottom()
+		} else {
+			Self{bounds: Some((lower, upper))}
+		}
+	}
+	
+	///
+	/// The interval only containing the given integer.
+	///
+	pub fn constant(v: i64) -> Self
+	{
+		Self::new(v, v)
+	}
+	
+	///
+	/// The interval of all integers larger than or equal to the given.
+	///
+	pub fn at_least(lower: i64) -> Self
+	{
+		Self::from_bounds(IntervalBound::Int(lower), IntervalBound::PosInf)
+	}
+	
+	///
+	/// The interval of all integers smaller than or equal to the given.
+	///
+	pub fn at_most(upper: i64) -> Self
+	{
+		Self::from_bounds(IntervalBound::NegInf, IntervalBound::Int(upper))
+	}
+	
+	///
+	/// The lower bound of the interval, or `None` if it is empty.
+	///
+	pub fn lower(&self) -> Option<IntervalBound>
+	{
+		self.bounds.map(|(l, _)| l)
+	}
+	
+	///
+	/// The upper bound of the interval, or `None` if it is empty.
+	///
+	pub fn upper(&self) -> Option<IntervalBound>
+	{
+		self.bounds.map(|(_, u)| u)
+	}
+	
+	///
+	/// Whether the given integer is in the interval.
+	///
+	pub fn contains(&self, v: i64) -> bool
+	{
+		match self.bounds {
+			Some((l, u)) => l <= IntervalBound::Int(v) && IntervalBound::Int(v) <= u,
+			None => false,
+		}
+	}
+	
+	///
+	/// Abstract addition.
+	///
+	pub fn plus(&self, other: &Self) -> Self
+	{
+		match (self.bounds, other.bounds) {
+			(Some((l1, u1)), Some((l2, u2))) =>
+				from_extended(ext_add(l1.into(), l2.into()), ext_add(u1.into(), u2.into())),
+			_ => Self::bottom(),
+		}
+	}
+	
+	///
+	/// Abstract negation.
+	///
+	pub fn negate(&self) -> Self
+	{
+		match self.bounds {
+			Some((l, u)) => from_extended(ext_neg(u.into()), ext_neg(l.into())),
+			None => Self::bottom(),
+		}
+	}
+	
+	///
+	/// Abstract subtraction.
+	///
+	pub fn minus(&self, other: &Self) -> Self
+	{
+		self.plus(&other.negate())
+	}
+	
+	///
+	/// Abstract multiplication.
+	///
+	pub fn times(&self, other: &Self) -> Self
+	{
+		match (self.bounds, other.bounds) {
+			(Some((l1, u1)), Some((l2, u2))) => {
+				let candidates = [
+					ext_mul(l1.into(), l2.into()), ext_mul(l1.into(), u2.into()),
+					ext_mul(u1.into(), l2.into()), ext_mul(u1.into(), u2.into()),
+				];
+				from_candidates(&candidates)
+			},
+			_ => Self::bottom(),
+		}
+	}
+	
+	///
+	/// Abstract integer division, rounding towards zero.
+	///
+	/// Division by zero has no result, so zero in the divisor is ignored.
+	///
+	pub fn divide(&self, other: &Self) -> Self
+	{
+		let (l1, u1) = match self.bounds {
+			Some(b) => b,
+			None => return Self::bottom(),
+		};
+		// Divide by the negative and positive parts of the divisor separately,
+		// as the quotient is monotone within each
+		let negative = other.meet(&Self::at_most(-1));
+		let positive = other.meet(&Self::at_least(1));
+		let mut result = Self::bottom();
+		for divisor in [negative, positive].iter() {
+			if let Some((l2, u2)) = divisor.bounds {
+				let mut candidates = Vec::new();
+				for &n in [l1, u1].iter() {
+					for &d in [l2, u2].iter() {
+						candidates.extend(ext_div(n.into(), d.into()));
+					}
+				}
+				result += from_candidates(&candidates);
+			}
+		}
+		result
+	}
+	
+	///
+	/// Abstract `<`.
+	///
+	pub fn less_than(&self, other: &Self) -> Flat<bool>
+	{
+		match (self.bounds, other.bounds) {
+			(Some((l1, u1)), Some((l2, u2))) =>
+				if u1 < l2 {
+					Flat::Value(true)
+				} else if l1 >= u2 {
+					Flat::Value(false)
+				} else {
+					Flat::Top
+				},
+			_ => Flat::Bottom,
+		}
+	}
+	
+	///
+	/// Abstract `<=`.
+	///
+	pub fn less_equal(&self, other: &Self) -> Flat<bool>
+	{
+		match (self.bounds, other.bounds) {
+			(Some((l1, u1)), Some((l2, u2))) =>
+				if u1 <= l2 {
+					Flat::Value(true)
+				} else if l1 > u2 {
+					Flat::Value(false)
+				} else {
+					Flat::Top
+				},
+			_ => Flat::Bottom,
+		}
+	}
+	
+	///
+	/// Abstract `>`.
+	///
+	pub fn greater_than(&self, other: &Self) -> Flat<bool>
+	{
+		other.less_than(self)
+	}
+	
+	///
+	/// Abstract `>=`.
+	///
+	pub fn greater_equal(&self, other: &Self) -> Flat<bool>
+	{
+		other.less_equal(self)
+	}
+	
+	///
+	/// Abstract `==`.
+	///
+	pub fn equal_to(&self, other: &Self) -> Flat<bool>
+	{
+		match (self.bounds, other.bounds) {
+			(Some((l1, u1)), Some((l2, u2))) =>
+				if l1 == u1 && l2 == u2 && l1 == l2 {
+					Flat::Value(true)
+				} else if self.meet(other).is_bottom() {
+					Flat::Value(false)
+				} else {
+					Flat::Top
+				},
+			_ => Flat::Bottom,
+		}
+	}
+	
+	///
+	/// Abstract `!=`.
+	///
+	pub fn not_equal(&self, other: &Self) -> Flat<bool>
+	{
+		match self.equal_to(other) {
+			Flat::Value(b) => Flat::Value(!b),
+			other => other,
+		}
+	}
+}
+
+impl CompleteLattice for Interval
+{
+	fn is_bottom(&self) -> bool
+	{
+		self.bounds.is_none()
+	}
+}
+
+impl Bottom for Interval
+{
+	fn bottom() -> Self
+	{
+		Self{bounds: None}
+	}
+}
+
+impl Top for Interval
+{
+	fn top() -> Self
+	{
+		Self{bounds: Some((IntervalBound::NegInf, IntervalBound::PosInf))}
+	}
+	
+	fn is_top(&self) -> bool
+	{
+		*self == Self::top()
+	}
+}
+
+impl Abstraction<i64> for Interval
+{
+	fn alpha(concrete: &i64) -> Self
+	{
+		Self::constant(*concrete)
+	}
+	
+	fn gamma_contains(&self, concrete: &i64) -> bool
+	{
+		self.contains(*concrete)
+	}
+}
+
+impl Meet for Interval
+{
+	fn meet_assign(&mut self, other: &Self)
+	{
+		*self = match (self.bounds, other.bounds) {
+			(Some((l1, u1)), Some((l2, u2))) => Self::from_bounds(max(l1, l2), min(u1, u2)),
+			_ => Self::bottom(),
+		};
+	}
+}
+
+impl Widen for Interval
+{
+	fn widen(&self, next: &Self) -> Self
+	{
+		match (self.bounds, next.bounds) {
+			(Some((l1, u1)), Some((l2, u2))) => {
+				let l = if l2 < l1 { IntervalBound::NegInf } else { l1 };
+				let u = if u2 > u1 { IntervalBound::PosInf } else { u1 };
+				Self::from_bounds(l, u)
+			},
+			_ => *self + next,
+		}
+	}
+}
+
+impl Narrow for Interval
+{
+	fn narrow(&self, next: &Self) -> Self
+	{
+		match (self.bounds, next.bounds) {
+			(Some((l1, u1)), Some((l2, u2))) => {
+				let l = if l1 == IntervalBound::NegInf { l2 } else { l1 };
+				let u = if u1 == IntervalBound::PosInf { u2 } else { u1 };
+				Self::from_bounds(l, u)
+			},
+			_ => Self::bottom(),
+		}
+	}
+}
+
+impl PartialOrd for Interval
+{
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		let self_le = self.meet(other) == *self;
+		let other_le = self.meet(other) == *other;
+		match (self_le, other_le) {
+			(true, true) => Some(Ordering::Equal),
+			(true, false) => Some(Ordering::Less),
+			(false, true) => Some(Ordering::Greater),
+			(false, false) => None,
+		}
+	}
+}
+
+impl Add for Interval
+{
+	type Output = Self;
+	fn add(mut self, rhs: Self) -> Self::Output
+	{
+		join(&mut self, &rhs);
+		self
+	}
+}
+
+impl<'a> Add<&'a Self> for Interval
+{
+	type Output = Self;
+	fn add(mut self, rhs: &'a Self) -> Self::Output
+	{
+		join(&mut self, rhs);
+		self
+	}
+}
+
+impl AddAssign for Interval
+{
+	fn add_assign(&mut self, rhs: Self)
+	{
+		join(self, &rhs);
+	}
+}
+
+impl<'a> AddAssign<&'a Self> for Interval
+{
+	fn add_assign(&mut self, rhs: &'a Self)
+	{
+		join(self, rhs);
+	}
+}
+
+impl Debug for Interval
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		match self.bounds {
+			Some((l, u)) => {
+				let fmt_bound = |b| match b {
+					IntervalBound::NegInf => "-inf".to_string(),
+					IntervalBound::Int(v) => v.to_string(),
+					IntervalBound::PosInf => "inf".to_string(),
+				};
+				write!(f, "[{}, {}]", fmt_bound(l), fmt_bound(u))
+			},
+			None => write!(f, "[]"),
+		}
+	}
+}
+
+// Helper functions
+
+fn join(left: &mut Interval, right: &Interval)
+{
+	if let Some((l2, u2)) = right.bounds {
+		left.bounds = match left.bounds {
+			Some((l1, u1)) => Some((min(l1, l2), max(u1, u2))),
+			None => Some((l2, u2)),
+		};
+	}
+}
+
+///
+/// An integer bound with enough precision to hold the result of
+/// any operation on two `i64`s.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Extended
+{
+	NegInf,
+	Int(i128),
+	PosInf,
+}
+
+impl From<IntervalBound> for Extended
+{
+	fn from(b: IntervalBound) -> Self
+	{
+		match b {
+			IntervalBound::NegInf => Extended::NegInf,
+			IntervalBound::Int(v) => Extended::Int(v as i128),
+			IntervalBound::PosInf => Extended::PosInf,
+		}
+	}
+}
+
+impl Extended
+{
+	/// The sign of the bound as -1, 0 or 1.
+	fn signum(self) -> i128
+	{
+		match self {
+			Extended::NegInf => -1,
+			Extended::Int(v) => v.signum(),
+			Extended::PosInf => 1,
+		}
+	}
+	
+	/// The infinity with the given sign, or 0.
+	fn infinity(signum: i128) -> Self
+	{
+		match signum.cmp(&0) {
+			Ordering::Less => Extended::NegInf,
+			Ordering::Equal => Extended::Int(0),
+			Ordering::Greater => Extended::PosInf,
+		}
+	}
+}
+
+/// Should not be called with opposite infinities.
+fn ext_add(a: Extended, b: Extended) -> Extended
+{
+	match (a, b) {
+		(Extended::Int(x), Extended::Int(y)) => Extended::Int(x + y),
+		(Extended::Int(_), inf) | (inf, _) => inf,
+	}
+}
+
+fn ext_neg(a: Extended) -> Extended
+{
+	match a {
+		Extended::NegInf => Extended::PosInf,
+		Extended::Int(v) => Extended::Int(-v),
+		Extended::PosInf => Extended::NegInf,
+	}
+}
+
+fn ext_mul(a: Extended, b: Extended) -> Extended
+{
+	match (a, b) {
+		(Extended::Int(x), Extended::Int(y)) => Extended::Int(x * y),
+		_ => Extended::infinity(a.signum() * b.signum()),
+	}
+}
+
+/// The possible extremes of `a / b`. `b` must not be 0.
+fn ext_div(a: Extended, b: Extended) -> Vec<Extended>
+{
+	match (a, b) {
+		(Extended::Int(x), Extended::Int(y)) => vec![Extended::Int(x / y)],
+		(Extended::Int(_), _) => vec![Extended::Int(0)],
+		(_, Extended::Int(y)) => vec![Extended::infinity(a.signum() * y.signum())],
+		// Quotients of arbitrarily large numbers can be anything from 0 to infinity
+		_ => vec![Extended::Int(0), Extended::infinity(a.signum() * b.signum())],
+	}
+}
+
+/// The smallest interval containing all the given candidate bounds.
+fn from_candidates(candidates: &[Extended]) -> Interval
+{
+	let lower = candidates.iter().min().cloned();
+	let upper = candidates.iter().max().cloned();
+	match (lower, upper) {
+		(Some(l), Some(u)) => from_extended(l, u),
+		_ => Interval::bottom(),
+	}
+}
+
+///
+/// Converts the given bounds to an interval.
+/// Bounds outside the range of `i64` are rounded outwards, such that
+/// the interval contains all integers between the given bounds.
+///
+fn from_extended(lower: Extended, upper: Extended) -> Interval
+{
+	let lower = match lower {
+		Extended::NegInf => IntervalBound::NegInf,
+		Extended::Int(v) if v < i64::MIN as i128 => IntervalBound::NegInf,
+		Extended::Int(v) if v > i64::MAX as i128 => IntervalBound::Int(i64::MAX),
+		Extended::Int(v) => IntervalBound::Int(v as i64),
+		Extended::PosInf => IntervalBound::PosInf,
+	};
+	let upper = match upper {
+		Extended::NegInf => IntervalBound::NegInf,
+		Extended::Int(v) if v > i64::MAX as i128 => IntervalBound::PosInf,
+		Extended::Int(v) if v < i64::MIN as i128 => IntervalBound::Int(i64::MIN),
+		Extended::Int(v) => IntervalBound::Int(v as i64),
+		Extended::PosInf => IntervalBound::PosInf,
+	};
+	Interval::from_bounds(lower, upper)
+}
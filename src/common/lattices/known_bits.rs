@@ -0,0 +1,547 @@
+use std::{
+	cmp::Ordering,
+	fmt::{
+		Debug, Formatter, Result as FmtResult
+	},
+	hash::Hash,
+	ops::{
+		Add, AddAssign, BitAnd, BitOr, BitXor, Not, Shl, Shr
+	}
+};
+use crate::core::{
+	CompleteLattice, Bottom, Top, Meet, Abstraction
+};
+use super::Flat;
+
+///
+/// An unsigned machine word, whose bits can be tracked by [`KnownBits`].
+///
+/// [`KnownBits`]: struct.KnownBits.html
+///
+pub trait Word: Copy + Ord + Hash + Debug
+	+ Not<Output=Self> + BitAnd<Output=Self> + BitOr<Output=Self> + BitXor<Output=Self>
+	+ Shl<u32, Output=Self> + Shr<u32, Output=Self>
+{
+	const BITS: u32;
+	const ZERO: Self;
+	const ONE: Self;
+	const MAX: Self;
+	
+	///
+	/// Adds the given word, wrapping around at the word size.
+	///
+	fn wrapping_add(self, other: Self) -> Self;
+	
+	///
+	/// The given value, truncated to the word size.
+	///
+	fn from_u32(v: u32) -> Self;
+}
+
+macro_rules! impl_word{
+	{
+		$($t:ty),*
+	}=>{
+		$(
+			impl Word for $t
+			{
+				const BITS: u32 = <$t>::BITS;
+				const ZERO: Self = 0;
+				const ONE: Self = 1;
+				const MAX: Self = <$t>::MAX;
+				
+				fn wrapping_add(self, other: Self) -> Self
+				{
+					<$t>::wrapping_add(self, other)
+				}
+				
+				fn from_u32(v: u32) -> Self
+				{
+					v as $t
+				}
+			}
+		)*
+	};
+}
+
+impl_word!(u8, u16, u32, u64);
+
+///
+/// The known bits abstract domain, tracking which bits of a machine word are known
+/// to be 0 or 1.
+///
+/// Each element is given by a mask of the bits known to be 0 and a mask of the bits known
+/// to be 1. Top knows no bits, while bottom, which describes no words, is represented by
+/// every bit being known to be both 0 and 1. Joining keeps the bits known by both.
+///
+/// The bitwise operations [`and`], [`or`], [`xor`] and [`not`] know a bit of the result
+/// whenever the known bits of the operands determine it, e.g. `and` knows every bit
+/// that is known to be 0 in either operand. [`shift_left`] and [`shift_right`] take the
+/// amount to shift by as a [`KnownBits`] too. [`plus`] and [`minus`] wrap around like
+/// machine arithmetic and know a bit of the result if the operand bits and the carry
+/// into it are known. The unsigned comparisons, e.g. [`less_than`], give a [`Flat`] result,
+/// which is only a value if the known bits decide the comparison.
+///
+/// [`and`]: #method.and
+/// [`or`]: #method.or
+/// [`xor`]: #method.xor
+/// [`not`]: #method.not
+/// [`shift_left`]: #method.shift_left
+/// [`shift_right`]: #method.shift_right
+/// [`plus`]: #method.plus
+/// [`minus`]: #method.minus
+/// [`less_than`]: #method.less_than
+/// [`KnownBits`]: struct.KnownBits.html
+/// [`Flat`]: enum.Flat.html
+///
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct KnownBits<W>
+	where
+		W: Word
+{
+	/// Invariant: If any bit is in both masks, all bits are.
+	zeros: W,
+	ones: W,
+}
+
+impl<W> KnownBits<W>
+	where
+		W: Word
+{
+	///
+	/// The element where the bits in `zeros` are known to be 0 and the bits in `ones`
+	/// are known to be 1.
+	///
+	/// Is bottom if any bit is in both masks.
+	///
+	pub fn new(zeros: W, ones: W) -> Self
+	{
+		if zeros & ones == W::ZERO {
+			Self{zeros, ones}
+		} else {
+			Self::bottom()
+		}
+	}
+	
+	///
+	/// The element where all bits are known to be those of the given word.
+	///
+	pub fn constant(v: W) -> Self
+	{
+		Self{zeros: !v, ones: v}
+	}
+	
+	///
+	/// The bits known to be 0, or `None` if this is bottom.
+	///
+	pub fn known_zeros(&self) -> Option<W>
+	{
+		self.known().map(|_| self.zeros)
+	}
+	
+	///
+	/// The bits known to be 1, or `None` if this is bottom.
+	///
+	pub fn known_ones(&self) -> Option<W>
+	{
+		self.known().map(|_| self.ones)
+	}
+	
+	///
+	/// The word described by this element, if all its bits are known.
+	///
+	pub fn value(&self) -> Option<W>
+	{
+		match self.known() {
+			Some(known) if known == W::MAX => Some(self.ones),
+			_ => None,
+		}
+	}
+	
+	///
+	/// Whether the given word has all the known bits.
+	///
+	pub fn contains(&self, v: W) -> bool
+	{
+		v & self.zeros == W::ZERO && v & self.ones == self.ones
+	}
+	
+	///
+	/// Abstract bitwise and.
+	///
+	pub fn and(&self, other: &Self) -> Self
+	{
+		self.combine(other, |a, b| Self{zeros: a.zeros | b.zeros, ones: a.ones & b.ones})
+	}
+	
+	///
+	/// Abstract bitwise or.
+	///
+	pub fn or(&self, other: &Self) -> Self
+	{
+		self.combine(other, |a, b| Self{zeros: a.zeros & b.zeros, ones: a.ones | b.ones})
+	}
+	
+	///
+	/// Abstract bitwise exclusive or.
+	///
+	pub fn xor(&self, other: &Self) -> Self
+	{
+		self.combine(other, |a, b| {
+			let known = (a.zeros | a.ones) & (b.zeros | b.ones);
+			let v = a.ones ^ b.ones;
+			Self{zeros: !v & known, ones: v & known}
+		})
+	}
+	
+	///
+	/// Abstract bitwise negation.
+	///
+	pub fn not(&self) -> Self
+	{
+		Self{zeros: self.ones, ones: self.zeros}
+	}
+	
+	///
+	/// Abstract left shift by the number of bits given by `amount`.
+	///
+	/// Shifting by the word size or more gives 0.
+	///
+	pub fn shift_left(&self, amount: &Self) -> Self
+	{
+		self.shift(amount, |a, n| Self{
+			zeros: (a.zeros << n) | !(W::MAX << n),
+			ones: a.ones << n,
+		})
+	}
+	
+	///
+	/// Abstract logical right shift by the number of bits given by `amount`.
+	///
+	/// Shifting by the word size or more gives 0.
+	///
+	pub fn shift_right(&self, amount: &Self) -> Self
+	{
+		self.shift(amount, |a, n| Self{
+			zeros: (a.zeros >> n) | !(W::MAX >> n),
+			ones: a.ones >> n,
+		})
+	}
+	
+	///
+	/// Abstract wrapping addition.
+	///
+	pub fn plus(&self, other: &Self) -> Self
+	{
+		self.combine(other, |a, b| add_with_carry(a, b, false))
+	}
+	
+	///
+	/// Abstract wrapping subtraction.
+	///
+	pub fn minus(&self, other: &Self) -> Self
+	{
+		// a - b == a + !b + 1
+		self.combine(other, |a, b| add_with_carry(a, &b.not(), true))
+	}
+	
+	///
+	/// Abstract `==`.
+	///
+	pub fn equal_to(&self, other: &Self) -> Flat<bool>
+	{
+		if self.is_bottom() || other.is_bottom() {
+			Flat::Bottom
+		} else if (self.zeros & other.ones) | (self.ones & other.zeros) != W::ZERO {
+			Flat::Value(false)
+		} else if self.value().is_some() && self.value() == other.value() {
+			Flat::Value(true)
+		} else {
+			Flat::Top
+		}
+	}
+	
+	///
+	/// Abstract `!=`.
+	///
+	pub fn not_equal(&self, other: &Self) -> Flat<bool>
+	{
+		match self.equal_to(other) {
+			Flat::Value(b) => Flat::Value(!b),
+			result => result,
+		}
+	}
+	
+	///
+	/// Abstract unsigned `<`.
+	///
+	pub fn less_than(&self, other: &Self) -> Flat<bool>
+	{
+		if self.is_bottom() || other.is_bottom() {
+			Flat::Bottom
+		} else if self.max() < other.min() {
+			Flat::Value(true)
+		} else if self.min() >= other.max() {
+			Flat::Value(false)
+		} else {
+			Flat::Top
+		}
+	}
+	
+	///
+	/// Abstract unsigned `<=`.
+	///
+	pub fn less_equal(&self, other: &Self) -> Flat<bool>
+	{
+		if self.is_bottom() || other.is_bottom() {
+			Flat::Bottom
+		} else if self.max() <= other.min() {
+			Flat::Value(true)
+		} else if self.min() > other.max() {
+			Flat::Value(false)
+		} else {
+			Flat::Top
+		}
+	}
+	
+	///
+	/// Abstract unsigned `>`.
+	///
+	pub fn greater_than(&self, other: &Self) -> Flat<bool>
+	{
+		other.less_than(self)
+	}
+	
+	///
+	/// Abstract unsigned `>=`.
+	///
+	pub fn greater_equal(&self, other: &Self) -> Flat<bool>
+	{
+		other.less_equal(self)
+	}
+	
+	/// The mask of known bits, or `None` if this is bottom.
+	fn known(&self) -> Option<W>
+	{
+		if self.is_bottom() {
+			None
+		} else {
+			Some(self.zeros | self.ones)
+		}
+	}
+	
+	/// The smallest word described, if not bottom.
+	fn min(&self) -> W
+	{
+		self.ones
+	}
+	
+	/// The largest word described, if not bottom.
+	fn max(&self) -> W
+	{
+		!self.zeros
+	}
+	
+	/// Applies the given operation, unless either operand is bottom.
+	fn combine<F>(&self, other: &Self, op: F) -> Self
+		where
+			F: FnOnce(&Self, &Self) -> Self
+	{
+		if self.is_bottom() || other.is_bottom() {
+			Self::bottom()
+		} else {
+			op(self, other)
+		}
+	}
+	
+	/// Joins the given shift for every amount `amount` may describe.
+	fn shift<F>(&self, amount: &Self, op: F) -> Self
+		where
+			F: Fn(&Self, u32) -> Self
+	{
+		let mut result = Self::bottom();
+		if self.is_bottom() {
+			return result;
+		}
+		for n in (0..W::BITS).filter(|n| amount.contains(W::from_u32(*n))) {
+			result += op(self, n);
+		}
+		if !amount.is_bottom() && amount.max() >= W::from_u32(W::BITS) {
+			result += Self::constant(W::ZERO);
+		}
+		result
+	}
+}
+
+impl<W> CompleteLattice for KnownBits<W>
+	where
+		W: Word
+{
+	fn is_bottom(&self) -> bool
+	{
+		self.zeros & self.ones != W::ZERO
+	}
+}
+
+impl<W> Bottom for KnownBits<W>
+	where
+		W: Word
+{
+	fn bottom() -> Self
+	{
+		Self{zeros: W::MAX, ones: W::MAX}
+	}
+}
+
+impl<W> Top for KnownBits<W>
+	where
+		W: Word
+{
+	fn top() -> Self
+	{
+		Self{zeros: W::ZERO, ones: W::ZERO}
+	}
+	
+	fn is_top(&self) -> bool
+	{
+		*self == Self::top()
+	}
+}
+
+impl<W> Meet for KnownBits<W>
+	where
+		W: Word
+{
+	fn meet_assign(&mut self, other: &Self)
+	{
+		*self = Self::new(self.zeros | other.zeros, self.ones | other.ones);
+	}
+}
+
+impl<W> Abstraction<W> for KnownBits<W>
+	where
+		W: Word
+{
+	fn alpha(concrete: &W) -> Self
+	{
+		Self::constant(*concrete)
+	}
+	
+	fn gamma_contains(&self, concrete: &W) -> bool
+	{
+		self.contains(*concrete)
+	}
+}
+
+impl<W> PartialOrd for KnownBits<W>
+	where
+		W: Word
+{
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		// An element is smaller if it knows more bits
+		let le = other.zeros & !self.zeros == W::ZERO && other.ones & !self.ones == W::ZERO;
+		let ge = self.zeros & !other.zeros == W::ZERO && self.ones & !other.ones == W::ZERO;
+		match (le, ge) {
+			(true, true) => Some(Ordering::Equal),
+			(true, false) => Some(Ordering::Less),
+			(false, true) => Some(Ordering::Greater),
+			(false, false) => None,
+		}
+	}
+}
+
+impl<W> Add for KnownBits<W>
+	where
+		W: Word
+{
+	type Output = Self;
+	fn add(mut self, rhs: Self) -> Self::Output
+	{
+		self += rhs;
+		self
+	}
+}
+
+impl<'a, W> Add<&'a Self> for KnownBits<W>
+	where
+		W: Word
+{
+	type Output = Self;
+	fn add(mut self, rhs: &'a Self) -> Self::Output
+	{
+		self += rhs;
+		self
+	}
+}
+
+impl<W> AddAssign for KnownBits<W>
+	where
+		W: Word
+{
+	fn add_assign(&mut self, rhs: Self)
+	{
+		*self += &rhs;
+	}
+}
+
+impl<'a, W> AddAssign<&'a Self> for KnownBits<W>
+	where
+		W: Word
+{
+	fn add_assign(&mut self, rhs: &'a Self)
+	{
+		self.zeros = self.zeros & rhs.zeros;
+		self.ones = self.ones & rhs.ones;
+	}
+}
+
+impl<W> Debug for KnownBits<W>
+	where
+		W: Word
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		if self.is_bottom() {
+			return write!(f, "0b_");
+		}
+		write!(f, "0b")?;
+		for i in (0..W::BITS).rev() {
+			let bit = W::ONE << i;
+			if self.zeros & bit != W::ZERO {
+				write!(f, "0")?;
+			} else if self.ones & bit != W::ZERO {
+				write!(f, "1")?;
+			} else {
+				write!(f, "?")?;
+			}
+		}
+		Ok(())
+	}
+}
+
+// Helper functions
+
+///
+/// Abstract `left + right + carry`, where neither operand is bottom.
+///
+/// A bit of the sum is known if the corresponding bits of both operands and the carry
+/// into it are known. The carries are known where the sums of the smallest and largest
+/// described words agree on them.
+///
+fn add_with_carry<W>(left: &KnownBits<W>, right: &KnownBits<W>, carry: bool) -> KnownBits<W>
+	where
+		W: Word
+{
+	let carry = if carry { W::ONE } else { W::ZERO };
+	let min_sum = left.min().wrapping_add(right.min()).wrapping_add(carry);
+	let max_sum = left.max().wrapping_add(right.max()).wrapping_add(carry);
+	
+	// The carry into each bit is the sum bit xor the operand bits
+	let carry_known_zero = !(max_sum ^ left.zeros ^ right.zeros);
+	let carry_known_one = min_sum ^ left.ones ^ right.ones;
+	
+	let known = (left.zeros | left.ones) & (right.zeros | right.ones)
+		& (carry_known_zero | carry_known_one);
+	KnownBits{zeros: !max_sum & known, ones: min_sum & known}
+}
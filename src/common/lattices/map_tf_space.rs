@@ -0,0 +1,263 @@
+
+///
+/// Implements the Total Function Space lattice for a struct wrapping a map from
+/// `std::collections` in a field called `map`.
+///
+/// Takes the name of the struct, the name of the map type, the name of the set type
+/// used to return the keys and the trait aliases the keys and elements must implement.
+///
+macro_rules! map_tf_space{
+	{
+		$name:ident, $map:ident, $set:ident, $key:ident, $element:ident
+	}=>{
+		impl<K,E> $crate::core::TFSpace<K,E> for $name<K,E>
+			where
+				K: $key,
+				E: $element
+		{
+			type Keys = <$set<K> as IntoIterator>::IntoIter;
+			
+			fn keys(&self) -> Self::Keys{
+				self.map.keys().cloned().collect::<$set<K>>().into_iter()
+			}
+			
+			fn has_key(&self, k: K) -> bool
+			{
+				self.map.contains_key(&k)
+			}
+			
+			fn get(&self, k: K) -> ::std::borrow::Cow<'_, E>
+			{
+				match self.map.get(&k) {
+					Some(e) => ::std::borrow::Cow::Borrowed(e),
+					None => ::std::borrow::Cow::Owned(E::bottom()),
+				}
+			}
+		}
+		
+		impl<K,E> $crate::core::CompleteLattice for $name<K,E>
+			where
+				K: $key,
+				E: $element
+		{
+			fn is_bottom(&self) -> bool
+			{
+				self.map.values().all(|e| e.is_bottom())
+			}
+			
+			fn join_assign(&mut self, other: &Self) -> bool
+			{
+				let mut changed = false;
+				for (k, o) in other.map.iter() {
+					match self.map.get_mut(k) {
+						Some(s) => changed |= s.join_assign(o),
+						None => {
+							// A missing key is implicitly bottom
+							changed |= !o.is_bottom();
+							self.map.insert(*k, o.clone());
+						},
+					}
+				}
+				changed
+			}
+		}
+		
+		impl<K,E> $crate::core::Bottom for $name<K,E>
+			where
+				K: $key,
+				E: $element
+		{
+			fn bottom() -> Self
+			{
+				Self{map: $map::new()}
+			}
+		}
+		
+		impl<K,E> $crate::core::Top for $name<K,E>
+			where
+				K: $key + $crate::core::Finite,
+				E: $element + $crate::core::Top
+		{
+			fn top() -> Self
+			{
+				Self{map: K::universe().into_iter().map(|k| (k, E::top())).collect()}
+			}
+			
+			fn is_top(&self) -> bool
+			{
+				K::universe().into_iter().all(|k| self.map.get(&k).map_or(false, |e| e.is_top()))
+			}
+		}
+		
+		impl<K,E> $crate::core::Meet for $name<K,E>
+			where
+				K: $key,
+				E: $element + $crate::core::Meet
+		{
+			fn meet_assign(&mut self, other: &Self)
+			{
+				// Keys missing in other are implicitly bottom, so their meet is too
+				self.map.retain(|k, _| other.map.contains_key(k));
+				for (k, e) in self.map.iter_mut() {
+					e.meet_assign(&other.map[k]);
+				}
+			}
+		}
+		
+		impl<K,E> ::std::cmp::PartialOrd for $name<K,E>
+			where
+				K: $key,
+				E: $element
+		{
+			fn partial_cmp(&self, other:&Self) -> Option<::std::cmp::Ordering>
+			{
+				if self.lt(other) {
+					Some(::std::cmp::Ordering::Less)
+				} else if self.gt(other){
+					Some(::std::cmp::Ordering::Greater)
+				} else if self == other {
+					Some(::std::cmp::Ordering::Equal)
+				}else{
+					None
+				}
+			}
+			
+			fn lt(&self, other: &Self) -> bool
+			{
+				self.le(other) && self != other
+			}
+			fn le(&self, other: &Self) -> bool
+			{
+				self.for_each_pair(other, |s_e, o_e| s_e <= o_e)
+			}
+			fn gt(&self, other: &Self) -> bool
+			{
+				self.ge(other) && self != other
+			}
+			fn ge(&self, other: &Self) -> bool
+			{
+				self.for_each_pair(other, |s_e, o_e| s_e >= o_e)
+			}
+		}
+		
+		impl<K,E> ::std::cmp::PartialEq for $name<K,E>
+			where
+				K: $key,
+				E: $element
+		{
+			fn eq(&self, other:&Self) -> bool
+			{
+				self.for_each_pair(other, |s_e, o_e| s_e == o_e)
+			}
+		}
+		
+		impl<K,E> ::std::ops::Add<Self> for $name<K,E>
+			where
+				K: $key,
+				E: $element
+		{
+			type Output = Self;
+			
+			fn add(mut self, other: Self) -> Self::Output
+			{
+				self.join(&other);
+				self
+			}
+		}
+		
+		impl<K,E> ::std::ops::Add<&Self> for $name<K,E>
+			where
+				K: $key,
+				E: $element
+		{
+			type Output = Self;
+			
+			fn add(mut self, other: &Self) -> Self::Output
+			{
+				self.join(other);
+				self
+			}
+		}
+		
+		impl<K,E> ::std::ops::AddAssign for $name<K,E>
+			where
+				K: $key,
+				E: $element
+		{
+			fn add_assign(&mut self, other: Self)
+			{
+				self.join(&other);
+			}
+		}
+		
+		impl<K,E> ::std::ops::AddAssign<&Self> for $name<K,E>
+			where
+				K: $key,
+				E: $element
+		{
+			fn add_assign(&mut self, other: &Self)
+			{
+				self.join(other);
+			}
+		}
+		
+		impl<K,E> ::std::ops::Index<K> for $name<K,E>
+			where
+				K: $key,
+				E: $element
+		{
+			type Output = E;
+			
+			fn index(&self, index: K) -> &Self::Output
+			{
+				&self.map[&index]
+			}
+		}
+		
+		impl<K,E> ::std::ops::IndexMut<K> for $name<K,E>
+			where
+				K: $key,
+				E: $element
+		{
+			fn index_mut(&mut self, index: K) -> &mut Self::Output
+			{
+				self.map.entry(index).or_insert_with(E::bottom)
+			}
+		}
+		
+		// Helper functions
+		
+		impl<K,E> $name<K,E>
+			where
+				K: $key,
+				E: $element
+		{
+			fn join(&mut self, other: &Self)
+			{
+				for (k, o) in other.map.iter() {
+					match self.map.get_mut(k) {
+						Some(s) => *s += o,
+						None => {
+							self.map.insert(*k, o.clone());
+						},
+					}
+				}
+			}
+			
+			///
+			/// Ensures that `f` holds for all value pairs (one from each argument)
+			/// for all the keys in either argument, where missing keys map to bottom.
+			///
+			fn for_each_pair<F>(&self, other: &Self, f: F) -> bool
+				where
+					F: Fn(&E,&E) -> bool
+			{
+				use $crate::core::TFSpace;
+				self.map.iter().all(|(k, s)| f(s, &other.get(*k)))
+					&& other.map.iter()
+						.filter(|(k, _)| !self.map.contains_key(k))
+						.all(|(k, o)| f(&self.get(*k), o))
+			}
+		}
+	};
+}
@@ -5,10 +5,35 @@
 //!
 //!
 
+#[macro_use]
+mod set_power_set;
+#[macro_use]
+mod map_tf_space;
+
 mod hash_power_set;
+mod btree_power_set;
+mod hash_intersection_power_set;
+mod bit_set_power_set;
 mod hash_tf_space;
+mod btree_tf_space;
+mod vec_tf_space;
+mod persistent_tf_space;
+mod flat;
+mod interval;
+mod known_bits;
+mod sign;
 
 pub use self::{
 	hash_power_set::*,
-	hash_tf_space::*
+	btree_power_set::*,
+	hash_intersection_power_set::*,
+	bit_set_power_set::*,
+	hash_tf_space::*,
+	btree_tf_space::*,
+	vec_tf_space::*,
+	persistent_tf_space::*,
+	flat::*,
+	interval::*,
+	known_bits::*,
+	sign::*,
 };
@@ -0,0 +1,556 @@
+
+use std::{
+	borrow::Cow,
+	cmp::Ordering,
+	collections::hash_map::DefaultHasher,
+	fmt::{
+		Debug, Formatter, Result as FmtResult
+	},
+	hash::{
+		Hash, Hasher
+	},
+	mem,
+	ops::{
+		Index, IndexMut, Add, AddAssign
+	},
+	rc::Rc,
+	vec::IntoIter,
+};
+use crate::core::{
+	CompleteLattice, Bottom, Top, Finite, Meet, TFSpace, TFSpaceKey, TFSpaceElement
+};
+
+trait_alias!(PersistentTFSpaceKey: TFSpaceKey, Hash);
+trait_alias!(PersistentTFSpaceElement: TFSpaceElement);
+
+/// The number of hash bits used at each level of the trie.
+const BITS: u32 = 5;
+
+///
+/// A persistent Total Function Space, stored as a hash array mapped trie whose nodes are
+/// shared between clones.
+///
+/// Cloning is O(1), while updating a key only copies the nodes on the path to it,
+/// leaving the rest shared. This makes it suitable for transfer functions that clone
+/// a large map and only change a few keys.
+///
+/// Joining and comparing skip any subtries that are shared between the two arguments,
+/// so they are cheap for elements derived from each other.
+///
+pub struct PersistentTFSpace<K,E>
+	where
+		K: PersistentTFSpaceKey,
+		E: PersistentTFSpaceElement
+{
+	root: Rc<Branch<K,E>>,
+}
+
+impl<K,E> PersistentTFSpace<K,E>
+	where
+		K: PersistentTFSpaceKey,
+		E: PersistentTFSpaceElement
+{
+	///
+	/// Whether the two elements share all their storage, meaning any change to one has
+	/// not been made to the other.
+	///
+	/// If so, they are equal, but the converse does not hold.
+	///
+	pub fn ptr_eq(&self, other: &Self) -> bool
+	{
+		Rc::ptr_eq(&self.root, &other.root)
+	}
+	
+	fn get_entry(&self, k: K) -> Option<&E>
+	{
+		self.root.get(hash(k), k, 0)
+	}
+	
+	fn get_or_insert(&mut self, k: K) -> &mut E
+	{
+		Rc::make_mut(&mut self.root).get_or_insert(hash(k), k, 0)
+	}
+}
+
+impl<K,E> TFSpace<K,E> for PersistentTFSpace<K,E>
+	where
+		K: PersistentTFSpaceKey,
+		E: PersistentTFSpaceElement
+{
+	type Keys = IntoIter<K>;
+	
+	fn keys(&self) -> Self::Keys
+	{
+		let mut keys = Vec::new();
+		self.root.all(&mut |_, k, _| {
+			keys.push(k);
+			true
+		});
+		keys.into_iter()
+	}
+	
+	fn has_key(&self, k: K) -> bool
+	{
+		self.get_entry(k).is_some()
+	}
+	
+	fn get(&self, k: K) -> Cow<'_, E>
+	{
+		match self.get_entry(k) {
+			Some(e) => Cow::Borrowed(e),
+			None => Cow::Owned(E::bottom()),
+		}
+	}
+}
+
+impl<K,E> CompleteLattice for PersistentTFSpace<K,E>
+	where
+		K: PersistentTFSpaceKey,
+		E: PersistentTFSpaceElement
+{
+	fn is_bottom(&self) -> bool
+	{
+		self.root.all(&mut |_, _, e| e.is_bottom())
+	}
+	
+	fn join_assign(&mut self, other: &Self) -> bool
+	{
+		join_branch(&mut self.root, &other.root, 0)
+	}
+}
+
+impl<K,E> Bottom for PersistentTFSpace<K,E>
+	where
+		K: PersistentTFSpaceKey,
+		E: PersistentTFSpaceElement
+{
+	fn bottom() -> Self
+	{
+		Self{root: Rc::new(Branch::empty())}
+	}
+}
+
+impl<K,E> Top for PersistentTFSpace<K,E>
+	where
+		K: PersistentTFSpaceKey + Finite,
+		E: PersistentTFSpaceElement + Top
+{
+	fn top() -> Self
+	{
+		let mut result = Self::bottom();
+		for k in K::universe() {
+			*result.get_or_insert(k) = E::top();
+		}
+		result
+	}
+	
+	fn is_top(&self) -> bool
+	{
+		K::universe().into_iter().all(|k| self.get_entry(k).is_some_and(|e| e.is_top()))
+	}
+}
+
+impl<K,E> Meet for PersistentTFSpace<K,E>
+	where
+		K: PersistentTFSpaceKey,
+		E: PersistentTFSpaceElement + Meet
+{
+	fn meet_assign(&mut self, other: &Self)
+	{
+		if self.ptr_eq(other) {
+			return;
+		}
+		// Rebuild from the keys present in both, as the meet of any other key is bottom.
+		let mut result = Self::bottom();
+		self.root.all(&mut |_, k, e| {
+			if let Some(o) = other.get_entry(k) {
+				*result.get_or_insert(k) = e.clone().meet(o);
+			}
+			true
+		});
+		*self = result;
+	}
+}
+
+impl<K,E> PartialOrd for PersistentTFSpace<K,E>
+	where
+		K: PersistentTFSpaceKey,
+		E: PersistentTFSpaceElement
+{
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		match (self.le(other), self.ge(other)) {
+			(true, true) => Some(Ordering::Equal),
+			(true, false) => Some(Ordering::Less),
+			(false, true) => Some(Ordering::Greater),
+			(false, false) => None,
+		}
+	}
+	
+	fn le(&self, other: &Self) -> bool
+	{
+		all_pairs_branch(&self.root, &other.root, 0, &|s, o| s <= o)
+	}
+	
+	fn ge(&self, other: &Self) -> bool
+	{
+		all_pairs_branch(&self.root, &other.root, 0, &|s, o| s >= o)
+	}
+}
+
+impl<K,E> PartialEq for PersistentTFSpace<K,E>
+	where
+		K: PersistentTFSpaceKey,
+		E: PersistentTFSpaceElement
+{
+	fn eq(&self, other: &Self) -> bool
+	{
+		all_pairs_branch(&self.root, &other.root, 0, &|s, o| s == o)
+	}
+}
+
+impl<K,E> Add<Self> for PersistentTFSpace<K,E>
+	where
+		K: PersistentTFSpaceKey,
+		E: PersistentTFSpaceElement
+{
+	type Output = Self;
+	
+	fn add(mut self, other: Self) -> Self::Output
+	{
+		self.join_assign(&other);
+		self
+	}
+}
+
+impl<K,E> Add<&Self> for PersistentTFSpace<K,E>
+	where
+		K: PersistentTFSpaceKey,
+		E: PersistentTFSpaceElement
+{
+	type Output = Self;
+	
+	fn add(mut self, other: &Self) -> Self::Output
+	{
+		self.join_assign(other);
+		self
+	}
+}
+
+impl<K,E> AddAssign for PersistentTFSpace<K,E>
+	where
+		K: PersistentTFSpaceKey,
+		E: PersistentTFSpaceElement
+{
+	fn add_assign(&mut self, other: Self)
+	{
+		self.join_assign(&other);
+	}
+}
+
+impl<K,E> AddAssign<&Self> for PersistentTFSpace<K,E>
+	where
+		K: PersistentTFSpaceKey,
+		E: PersistentTFSpaceElement
+{
+	fn add_assign(&mut self, other: &Self)
+	{
+		self.join_assign(other);
+	}
+}
+
+impl<K,E> Index<K> for PersistentTFSpace<K,E>
+	where
+		K: PersistentTFSpaceKey,
+		E: PersistentTFSpaceElement
+{
+	type Output = E;
+	
+	fn index(&self, index: K) -> &Self::Output
+	{
+		self.get_entry(index).expect("key not present")
+	}
+}
+
+impl<K,E> IndexMut<K> for PersistentTFSpace<K,E>
+	where
+		K: PersistentTFSpaceKey,
+		E: PersistentTFSpaceElement
+{
+	fn index_mut(&mut self, index: K) -> &mut Self::Output
+	{
+		self.get_or_insert(index)
+	}
+}
+
+impl<K,E> Clone for PersistentTFSpace<K,E>
+	where
+		K: PersistentTFSpaceKey,
+		E: PersistentTFSpaceElement
+{
+	fn clone(&self) -> Self
+	{
+		Self{root: self.root.clone()}
+	}
+}
+
+impl<K,E> Debug for PersistentTFSpace<K,E>
+	where
+		K: PersistentTFSpaceKey + Debug,
+		E: PersistentTFSpaceElement + Debug
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		let mut map = f.debug_map();
+		self.root.all(&mut |_, k, e| {
+			map.entry(&k, e);
+			true
+		});
+		map.finish()
+	}
+}
+
+///
+/// A node of the trie, with a slot for each set bit of its bitmap.
+///
+#[derive(Clone)]
+struct Branch<K,E>
+{
+	bitmap: u32,
+	slots: Vec<Slot<K,E>>,
+}
+
+#[derive(Clone)]
+enum Slot<K,E>
+{
+	///
+	/// The entries whose keys have the given full hash. Usually only one.
+	///
+	Leaf(Rc<(u64, Vec<(K,E)>)>),
+	Branch(Rc<Branch<K,E>>),
+}
+
+impl<K,E> Branch<K,E>
+	where
+		K: PersistentTFSpaceKey,
+		E: PersistentTFSpaceElement
+{
+	fn empty() -> Self
+	{
+		Self{bitmap: 0, slots: Vec::new()}
+	}
+	
+	/// The position in `slots` of the slot for the given bit.
+	fn index(&self, bit: u32) -> usize
+	{
+		(self.bitmap & (bit - 1)).count_ones() as usize
+	}
+	
+	fn slot(&self, bit: u32) -> Option<&Slot<K,E>>
+	{
+		if self.bitmap & bit == 0 {
+			None
+		} else {
+			Some(&self.slots[self.index(bit)])
+		}
+	}
+	
+	fn get(&self, hash: u64, k: K, shift: u32) -> Option<&E>
+	{
+		self.slot(bit(hash, shift)).and_then(|s| s.get(hash, k, shift + BITS))
+	}
+	
+	fn get_or_insert(&mut self, hash: u64, k: K, shift: u32) -> &mut E
+	{
+		let bit = bit(hash, shift);
+		let i = self.index(bit);
+		if self.bitmap & bit == 0 {
+			self.bitmap |= bit;
+			self.slots.insert(i, Slot::Leaf(Rc::new((hash, Vec::new()))));
+		}
+		self.slots[i].get_or_insert(hash, k, shift + BITS)
+	}
+	
+	/// Whether `f` holds for all entries, stopping at the first that it doesn't.
+	fn all<F>(&self, f: &mut F) -> bool
+		where
+			F: FnMut(u64, K, &E) -> bool
+	{
+		self.slots.iter().all(|s| s.all(f))
+	}
+}
+
+impl<K,E> Slot<K,E>
+	where
+		K: PersistentTFSpaceKey,
+		E: PersistentTFSpaceElement
+{
+	/// Looks up the key, where `shift` is that of a branch at this slot.
+	fn get(&self, hash: u64, k: K, shift: u32) -> Option<&E>
+	{
+		match self {
+			Slot::Branch(b) => b.get(hash, k, shift),
+			Slot::Leaf(leaf) => if leaf.0 == hash {
+				leaf.1.iter().find(|(key, _)| *key == k).map(|(_, e)| e)
+			} else {
+				None
+			},
+		}
+	}
+	
+	fn get_or_insert(&mut self, hash: u64, k: K, shift: u32) -> &mut E
+	{
+		let leaf_hash = match self {
+			Slot::Leaf(leaf) => Some(leaf.0),
+			Slot::Branch(_) => None,
+		};
+		match leaf_hash {
+			Some(h) if h != hash => {
+				// Push the leaf down into a new branch to make room for the key
+				let mut branch = Branch::empty();
+				branch.bitmap = bit(h, shift);
+				branch.slots.push(mem::replace(self, Slot::Branch(Rc::new(Branch::empty()))));
+				*self = Slot::Branch(Rc::new(branch));
+				self.get_or_insert(hash, k, shift)
+			},
+			_ => match self {
+				Slot::Branch(b) => Rc::make_mut(b).get_or_insert(hash, k, shift),
+				Slot::Leaf(leaf) => {
+					let entries = &mut Rc::make_mut(leaf).1;
+					let i = match entries.iter().position(|(key, _)| *key == k) {
+						Some(i) => i,
+						None => {
+							entries.push((k, E::bottom()));
+							entries.len() - 1
+						}
+					};
+					&mut entries[i].1
+				},
+			},
+		}
+	}
+	
+	fn all<F>(&self, f: &mut F) -> bool
+		where
+			F: FnMut(u64, K, &E) -> bool
+	{
+		match self {
+			Slot::Branch(b) => b.all(f),
+			Slot::Leaf(leaf) => leaf.1.iter().all(|(k, e)| f(leaf.0, *k, e)),
+		}
+	}
+}
+
+// Helper functions
+
+fn hash<K: Hash>(k: K) -> u64
+{
+	let mut hasher = DefaultHasher::new();
+	k.hash(&mut hasher);
+	hasher.finish()
+}
+
+/// The bit in a branch's bitmap that the given hash maps to at the given level.
+fn bit(hash: u64, shift: u32) -> u32
+{
+	1 << ((hash >> shift) & ((1 << BITS) - 1))
+}
+
+/// Joins `right` into `left`, returning whether `left` changed.
+fn join_branch<K,E>(left: &mut Rc<Branch<K,E>>, right: &Rc<Branch<K,E>>, shift: u32) -> bool
+	where
+		K: PersistentTFSpaceKey,
+		E: PersistentTFSpaceElement
+{
+	if Rc::ptr_eq(left, right) || right.bitmap == 0 {
+		return false;
+	}
+	let left = Rc::make_mut(left);
+	let mut changed = false;
+	let mut bits = right.bitmap;
+	while bits != 0 {
+		let bit = bits & bits.wrapping_neg();
+		bits &= !bit;
+		let r = right.slot(bit).unwrap();
+		let i = left.index(bit);
+		if left.bitmap & bit == 0 {
+			// Share the whole subtrie. It was implicitly bottom before.
+			changed |= !r.all(&mut |_, _, e| e.is_bottom());
+			left.bitmap |= bit;
+			left.slots.insert(i, r.clone());
+		} else {
+			changed |= join_slot(&mut left.slots[i], r, shift + BITS);
+		}
+	}
+	changed
+}
+
+fn join_slot<K,E>(left: &mut Slot<K,E>, right: &Slot<K,E>, shift: u32) -> bool
+	where
+		K: PersistentTFSpaceKey,
+		E: PersistentTFSpaceElement
+{
+	match (&mut *left, right) {
+		(Slot::Branch(l), Slot::Branch(r)) => join_branch(l, r, shift),
+		(Slot::Leaf(l), Slot::Leaf(r)) if Rc::ptr_eq(l, r) => false,
+		_ => {
+			let mut changed = false;
+			right.all(&mut |h, k, e| {
+				changed |= left.get_or_insert(h, k, shift).join_assign(e);
+				true
+			});
+			changed
+		},
+	}
+}
+
+///
+/// Whether `f` holds for all value pairs (one from each argument) for all the keys in
+/// either argument, where missing keys map to bottom.
+///
+/// `f` must be reflexive, as shared subtries are skipped.
+///
+fn all_pairs_branch<K,E,F>(left: &Rc<Branch<K,E>>, right: &Rc<Branch<K,E>>, shift: u32, f: &F)
+	-> bool
+	where
+		K: PersistentTFSpaceKey,
+		E: PersistentTFSpaceElement,
+		F: Fn(&E,&E) -> bool
+{
+	if Rc::ptr_eq(left, right) {
+		return true;
+	}
+	let bottom = E::bottom();
+	let mut bits = left.bitmap | right.bitmap;
+	while bits != 0 {
+		let bit = bits & bits.wrapping_neg();
+		bits &= !bit;
+		let holds = match (left.slot(bit), right.slot(bit)) {
+			(Some(l), Some(r)) => all_pairs_slot(l, r, shift + BITS, f),
+			(Some(l), None) => l.all(&mut |_, _, e| f(e, &bottom)),
+			(None, Some(r)) => r.all(&mut |_, _, e| f(&bottom, e)),
+			(None, None) => unreachable!("Bit is from one of the bitmaps"),
+		};
+		if !holds {
+			return false;
+		}
+	}
+	true
+}
+
+fn all_pairs_slot<K,E,F>(left: &Slot<K,E>, right: &Slot<K,E>, shift: u32, f: &F) -> bool
+	where
+		K: PersistentTFSpaceKey,
+		E: PersistentTFSpaceElement,
+		F: Fn(&E,&E) -> bool
+{
+	match (left, right) {
+		(Slot::Branch(l), Slot::Branch(r)) => all_pairs_branch(l, r, shift, f),
+		(Slot::Leaf(l), Slot::Leaf(r)) if Rc::ptr_eq(l, r) => true,
+		_ => {
+			let bottom = E::bottom();
+			left.all(&mut |h, k, e| f(e, right.get(h, k, shift).unwrap_or(&bottom)))
+				&& right.all(&mut |h, k, e| left.get(h, k, shift).is_some() || f(&bottom, e))
+		},
+	}
+}
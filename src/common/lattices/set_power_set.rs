@@ -0,0 +1,232 @@
+
+///
+/// Implements the [Power Set] lattice for a struct wrapping a set from
+/// `std::collections` in a field called `set`.
+///
+/// Takes the name of the struct, the name of the set type and the trait alias
+/// the items must implement.
+///
+/// [Power Set]: http://mathworld.wolfram.com/PowerSet.html
+///
+macro_rules! set_power_set{
+	{
+		$name:ident, $set:ident, $item:ident
+	}=>{
+		impl<E> $crate::core::PowerSet for $name<E>
+			where
+				E: $item
+		{
+			type Item = E;
+			type All = $set<E>;
+			type Iter<'a> = ::std::iter::Map<
+				<&'a $set<E> as IntoIterator>::IntoIter,
+				fn(&'a E) -> ::std::borrow::Cow<'a, E>
+			> where Self: 'a;
+			
+			fn singleton(s: Self::Item) -> Self
+			{
+				let mut set = $set::new();
+				set.insert(s);
+				Self{set}
+			}
+			
+			fn all(&self) -> Self::All{
+				self.set.clone()
+			}
+			
+			fn iter<'a>(&'a self) -> Self::Iter<'a>
+			{
+				self.set.iter().map(::std::borrow::Cow::Borrowed as fn(&'a E) -> _)
+			}
+			
+			fn contains(&self, item: &E) -> bool
+			{
+				self.set.contains(item)
+			}
+			
+			fn len(&self) -> usize
+			{
+				self.set.len()
+			}
+			
+			fn insert(&mut self, item: E) -> bool
+			{
+				self.set.insert(item)
+			}
+			
+			fn remove(&mut self, item: &E) -> bool
+			{
+				self.set.remove(item)
+			}
+			
+			fn from_iter<F>(i: F) -> Self
+				where F: IntoIterator<Item=Self::Item>
+			{
+				Self{set: i.into_iter().collect()}
+			}
+		}
+		
+		impl<E> ::std::cmp::PartialOrd for $name<E>
+			where
+				E: $item
+		{
+			fn partial_cmp(&self, other:&Self) -> Option<::std::cmp::Ordering>
+			{
+				let self_subset = self.set.is_subset(&other.set);
+				let other_subset = other.set.is_subset(&self.set);
+				if self_subset {
+					if other_subset {
+						Some(::std::cmp::Ordering::Equal)
+					} else {
+						Some(::std::cmp::Ordering::Less)
+					}
+				} else if other_subset {
+					Some(::std::cmp::Ordering::Greater)
+				} else {
+					None
+				}
+			}
+		}
+		
+		impl<E> ::std::cmp::PartialEq for $name<E>
+			where
+				E: $item
+		{
+			fn eq(&self, other:&Self) -> bool
+			{
+				self.set == other.set
+			}
+		}
+		
+		impl<'a,E> ::std::ops::Add<&'a Self> for $name<E>
+			where
+				E: $item
+		{
+			type Output = Self;
+			
+			fn add(mut self, other: &'a Self) -> Self::Output
+			{
+				self += other;
+				self
+			}
+		}
+		
+		impl<E> ::std::ops::Add<Self> for $name<E>
+			where
+				E: $item
+		{
+			type Output = Self;
+			
+			fn add(mut self, other: Self) -> Self::Output
+			{
+				self += other;
+				self
+			}
+		}
+		
+		impl<E> ::std::ops::AddAssign for $name<E>
+			where
+				E: $item
+		{
+			fn add_assign(&mut self, rhs: Self)
+			{
+				if self.set.len() < rhs.set.len() {
+					// Move the smaller set into the larger
+					let smaller = ::std::mem::replace(&mut self.set, rhs.set);
+					self.set.extend(smaller);
+				} else {
+					self.set.extend(rhs.set);
+				}
+			}
+		}
+		
+		impl<'a,E> ::std::ops::AddAssign<&'a Self> for $name<E>
+			where
+				E: $item
+		{
+			fn add_assign(&mut self, rhs: &'a Self)
+			{
+				for e in rhs.set.iter(){
+					if !self.set.contains(e) {
+						self.set.insert(e.clone());
+					}
+				}
+			}
+		}
+		
+		impl<E> $crate::core::CompleteLattice for $name<E>
+			where
+				E: $item
+		{
+			fn is_bottom(&self) -> bool
+			{
+				self.set.is_empty()
+			}
+			
+			fn join_assign(&mut self, other: &Self) -> bool
+			{
+				let mut changed = false;
+				for e in other.set.iter(){
+					if !self.set.contains(e) {
+						self.set.insert(e.clone());
+						changed = true;
+					}
+				}
+				changed
+			}
+		}
+		
+		impl<E> $crate::core::Bottom for $name<E>
+			where
+				E: $item
+		{
+			fn bottom() -> Self
+			{
+				Self{set: $set::new()}
+			}
+		}
+		
+		impl<E> $crate::core::Top for $name<E>
+			where
+				E: $item + $crate::core::Finite
+		{
+			fn top() -> Self
+			{
+				Self{set: E::universe().into_iter().collect()}
+			}
+			
+			fn is_top(&self) -> bool
+			{
+				E::universe().iter().all(|e| self.set.contains(e))
+			}
+		}
+		
+		impl<E> $crate::core::Meet for $name<E>
+			where
+				E: $item
+		{
+			fn meet_assign(&mut self, other: &Self)
+			{
+				self.set.retain(|e| other.set.contains(e));
+			}
+		}
+		
+		impl<E> ::std::convert::From<$set<E>> for $name<E>
+			where E: $item
+		{
+			fn from(set: $set<E>) -> Self
+			{
+				Self{set}
+			}
+		}
+		
+		impl<E> ::std::convert::From<$name<E>> for $set<E>
+			where E: $item
+		{
+			fn from(set: $name<E>) -> Self
+			{
+				set.set
+			}
+		}
+	};
+}
@@ -0,0 +1,245 @@
+
+use crate::core::{
+	PowerSet, Finite, Abstraction
+};
+use super::{
+	HashPowerSet, Flat
+};
+use self::Sign::*;
+
+///
+/// The sign of an integer.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Sign
+{
+	Plus,
+	Zero,
+	Minus,
+}
+
+impl Sign
+{
+	///
+	/// The sign of the given integer.
+	///
+	pub fn of(v: i64) -> Self
+	{
+		if v > 0 {
+			Plus
+		} else if v == 0 {
+			Zero
+		} else {
+			Minus
+		}
+	}
+}
+
+impl Finite for Sign
+{
+	fn universe() -> Vec<Self>
+	{
+		vec![Plus, Zero, Minus]
+	}
+}
+
+///
+/// The sign abstract domain, abstracting integers by the set of signs they may have.
+///
+/// The arithmetic methods [`plus`], [`increment`], [`minus`], [`times`], [`divide`] and
+/// [`negate`] apply the operation to every pair of signs and join the possible results,
+/// e.g. adding a positive and a negative integer may give any sign.
+///
+/// [`plus`]: struct.HashPowerSet.html#method.plus
+/// [`increment`]: struct.HashPowerSet.html#method.increment
+/// [`minus`]: struct.HashPowerSet.html#method.minus
+/// [`times`]: struct.HashPowerSet.html#method.times
+/// [`divide`]: struct.HashPowerSet.html#method.divide
+/// [`negate`]: struct.HashPowerSet.html#method.negate
+///
+pub type SignPowerSet = HashPowerSet<Sign>;
+
+impl HashPowerSet<Sign>
+{
+	///
+	/// Abstract addition.
+	///
+	pub fn plus(&self, other: &Self) -> Self
+	{
+		pairwise(self, other, |s1, s2| match (s1, s2) {
+			(Zero, s) | (s, Zero) => vec![s],
+			(Plus, Plus) => vec![Plus],
+			(Minus, Minus) => vec![Minus],
+			_ => vec![Plus, Zero, Minus],
+		})
+	}
+	
+	///
+	/// Abstract increment by one.
+	///
+	/// More precise than adding `{Plus}`, as e.g. the successor of a negative integer
+	/// can't be positive.
+	///
+	pub fn increment(&self) -> Self
+	{
+		Self::from_iter(self.iter().flat_map(|s| match *s {
+			Minus => vec![Minus, Zero],
+			Zero | Plus => vec![Plus],
+		}))
+	}
+	
+	///
+	/// Abstract negation.
+	///
+	pub fn negate(&self) -> Self
+	{
+		Self::from_iter(self.iter().map(|s| match *s {
+			Plus => Minus,
+			Zero => Zero,
+			Minus => Plus,
+		}))
+	}
+	
+	///
+	/// Abstract subtraction.
+	///
+	pub fn minus(&self, other: &Self) -> Self
+	{
+		self.plus(&other.negate())
+	}
+	
+	///
+	/// Abstract multiplication.
+	///
+	pub fn times(&self, other: &Self) -> Self
+	{
+		pairwise(self, other, |s1, s2| match (s1, s2) {
+			(Zero, _) | (_, Zero) => vec![Zero],
+			_ if s1 == s2 => vec![Plus],
+			_ => vec![Minus],
+		})
+	}
+	
+	///
+	/// Abstract integer division, rounding towards zero.
+	///
+	/// Division by zero has no result, so zero in the divisor is ignored.
+	///
+	pub fn divide(&self, other: &Self) -> Self
+	{
+		pairwise(self, other, |s1, s2| match (s1, s2) {
+			(_, Zero) => vec![],
+			(Zero, _) => vec![Zero],
+			_ if s1 == s2 => vec![Plus, Zero],
+			_ => vec![Minus, Zero],
+		})
+	}
+	
+	///
+	/// Abstract `<`.
+	///
+	pub fn less_than(&self, other: &Self) -> Flat<bool>
+	{
+		compare(self, other, |s1, s2| match (s1, s2) {
+			(Plus, Plus) | (Minus, Minus) => Flat::Top,
+			_ => Flat::Value(rank(s1) < rank(s2)),
+		})
+	}
+	
+	///
+	/// Abstract `<=`.
+	///
+	pub fn less_equal(&self, other: &Self) -> Flat<bool>
+	{
+		compare(self, other, |s1, s2| match (s1, s2) {
+			(Plus, Plus) | (Minus, Minus) => Flat::Top,
+			_ => Flat::Value(rank(s1) <= rank(s2)),
+		})
+	}
+	
+	///
+	/// Abstract `>`.
+	///
+	pub fn greater_than(&self, other: &Self) -> Flat<bool>
+	{
+		other.less_than(self)
+	}
+	
+	///
+	/// Abstract `>=`.
+	///
+	pub fn greater_equal(&self, other: &Self) -> Flat<bool>
+	{
+		other.less_equal(self)
+	}
+	
+	///
+	/// Abstract `==`.
+	///
+	pub fn equal_to(&self, other: &Self) -> Flat<bool>
+	{
+		compare(self, other, |s1, s2| match (s1, s2) {
+			(Plus, Plus) | (Minus, Minus) => Flat::Top,
+			_ => Flat::Value(s1 == s2),
+		})
+	}
+	
+	///
+	/// Abstract `!=`.
+	///
+	pub fn not_equal(&self, other: &Self) -> Flat<bool>
+	{
+		match self.equal_to(other) {
+			Flat::Value(b) => Flat::Value(!b),
+			other => other,
+		}
+	}
+}
+
+impl Abstraction<i64> for HashPowerSet<Sign>
+{
+	fn alpha(concrete: &i64) -> Self
+	{
+		Self::singleton(Sign::of(*concrete))
+	}
+	
+	fn gamma_contains(&self, concrete: &i64) -> bool
+	{
+		self.contains(&Sign::of(*concrete))
+	}
+}
+
+// Helper functions
+
+/// Applies the given operation to all pairs of signs, joining the results.
+fn pairwise<F>(left: &SignPowerSet, right: &SignPowerSet, op: F) -> SignPowerSet
+	where
+		F: Fn(Sign, Sign) -> Vec<Sign>
+{
+	SignPowerSet::from_iter(left.iter()
+		.flat_map(|s1| right.iter().flat_map(|s2| op(*s1, *s2)).collect::<Vec<_>>()))
+}
+
+/// Applies the given comparison to all pairs of signs, joining the results.
+fn compare<F>(left: &SignPowerSet, right: &SignPowerSet, op: F) -> Flat<bool>
+	where
+		F: Fn(Sign, Sign) -> Flat<bool>
+{
+	let mut result = Flat::Bottom;
+	for s1 in left.iter() {
+		for s2 in right.iter() {
+			result += op(*s1, *s2);
+		}
+	}
+	result
+}
+
+/// Orders the signs by the integers they represent.
+fn rank(s: Sign) -> i8
+{
+	match s {
+		Minus => -1,
+		Zero => 0,
+		Plus => 1,
+	}
+}
@@ -0,0 +1,255 @@
+
+use std::{
+	borrow::Cow,
+	cmp::Ordering,
+	ops::{
+		Index, IndexMut, Add, AddAssign, Range
+	},
+};
+use crate::core::{
+	CompleteLattice, Bottom, Meet, TFSpace, TFSpaceElement
+};
+
+trait_alias!(VecTFSpaceElement: TFSpaceElement);
+
+///
+/// A Total Function Space over densely numbered keys, stored contiguously.
+///
+/// The keys are the indices `0..n`, where `n` is one more than the largest key that has
+/// been indexed mutably. Indexing mutably past the end grows the storage, filling it with
+/// bottom, so it should only be used when the keys start from 0 and have few gaps.
+///
+/// Joining and comparing are done pointwise over the storage, without hashing or
+/// collecting the keys.
+///
+#[derive(Clone, Debug)]
+pub struct VecTFSpace<E>
+	where
+		E: VecTFSpaceElement
+{
+	values: Vec<E>,
+}
+
+impl<E> TFSpace<usize,E> for VecTFSpace<E>
+	where
+		E: VecTFSpaceElement
+{
+	type Keys = Range<usize>;
+	
+	fn keys(&self) -> Self::Keys
+	{
+		0..self.values.len()
+	}
+	
+	fn add_key(&mut self, k: usize)
+	{
+		grow(self, k);
+		self.values[k] = E::bottom();
+	}
+	
+	fn has_key(&self, k: usize) -> bool
+	{
+		k < self.values.len()
+	}
+	
+	fn get(&self, k: usize) -> Cow<'_, E>
+	{
+		match self.values.get(k) {
+			Some(e) => Cow::Borrowed(e),
+			None => Cow::Owned(E::bottom()),
+		}
+	}
+}
+
+impl<E> CompleteLattice for VecTFSpace<E>
+	where
+		E: VecTFSpaceElement
+{
+	fn is_bottom(&self) -> bool
+	{
+		self.values.iter().all(|e| e.is_bottom())
+	}
+	
+	fn join_assign(&mut self, other: &Self) -> bool
+	{
+		let mut changed = false;
+		for (s, o) in self.values.iter_mut().zip(other.values.iter()) {
+			changed |= s.join_assign(o);
+		}
+		if self.values.len() < other.values.len() {
+			let tail = &other.values[self.values.len()..];
+			// The missing keys were implicitly bottom
+			changed |= !tail.iter().all(|o| o.is_bottom());
+			self.values.extend_from_slice(tail);
+		}
+		changed
+	}
+}
+
+impl<E> Bottom for VecTFSpace<E>
+	where
+		E: VecTFSpaceElement
+{
+	fn bottom() -> Self
+	{
+		Self{values: Vec::new()}
+	}
+}
+
+impl<E> Meet for VecTFSpace<E>
+	where
+		E: VecTFSpaceElement + Meet
+{
+	fn meet_assign(&mut self, other: &Self)
+	{
+		// The meet with the bottom values past the end of 'other' is bottom, so drop them
+		self.values.truncate(other.values.len());
+		for (s, o) in self.values.iter_mut().zip(other.values.iter()) {
+			s.meet_assign(o);
+		}
+	}
+}
+
+impl<E> PartialOrd for VecTFSpace<E>
+	where
+		E: VecTFSpaceElement
+{
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		match (self.le(other), self.ge(other)) {
+			(true, true) => Some(Ordering::Equal),
+			(true, false) => Some(Ordering::Less),
+			(false, true) => Some(Ordering::Greater),
+			(false, false) => None,
+		}
+	}
+	
+	fn le(&self, other: &Self) -> bool
+	{
+		for_each_pair(self, other, |s, o| s <= o)
+	}
+	
+	fn ge(&self, other: &Self) -> bool
+	{
+		for_each_pair(self, other, |s, o| s >= o)
+	}
+}
+
+impl<E> PartialEq for VecTFSpace<E>
+	where
+		E: VecTFSpaceElement
+{
+	fn eq(&self, other: &Self) -> bool
+	{
+		for_each_pair(self, other, |s, o| s == o)
+	}
+}
+
+impl<E> Add<Self> for VecTFSpace<E>
+	where
+		E: VecTFSpaceElement
+{
+	type Output = Self;
+	
+	fn add(mut self, other: Self) -> Self::Output
+	{
+		join(&mut self, &other);
+		self
+	}
+}
+
+impl<E> Add<&Self> for VecTFSpace<E>
+	where
+		E: VecTFSpaceElement
+{
+	type Output = Self;
+	
+	fn add(mut self, other: &Self) -> Self::Output
+	{
+		join(&mut self, other);
+		self
+	}
+}
+
+impl<E> AddAssign for VecTFSpace<E>
+	where
+		E: VecTFSpaceElement
+{
+	fn add_assign(&mut self, other: Self)
+	{
+		join(self, &other);
+	}
+}
+
+impl<E> AddAssign<&Self> for VecTFSpace<E>
+	where
+		E: VecTFSpaceElement
+{
+	fn add_assign(&mut self, other: &Self)
+	{
+		join(self, other);
+	}
+}
+
+impl<E> Index<usize> for VecTFSpace<E>
+	where
+		E: VecTFSpaceElement
+{
+	type Output = E;
+	
+	fn index(&self, index: usize) -> &Self::Output
+	{
+		&self.values[index]
+	}
+}
+
+impl<E> IndexMut<usize> for VecTFSpace<E>
+	where
+		E: VecTFSpaceElement
+{
+	fn index_mut(&mut self, index: usize) -> &mut Self::Output
+	{
+		grow(self, index);
+		&mut self.values[index]
+	}
+}
+
+// Helper functions
+
+/// Makes sure the given key is present, mapping any new keys to bottom.
+fn grow<E>(space: &mut VecTFSpace<E>, k: usize)
+	where
+		E: VecTFSpaceElement
+{
+	if k >= space.values.len() {
+		space.values.resize(k + 1, E::bottom());
+	}
+}
+
+fn join<E>(left: &mut VecTFSpace<E>, right: &VecTFSpace<E>)
+	where
+		E: VecTFSpaceElement
+{
+	for (l, r) in left.values.iter_mut().zip(right.values.iter()) {
+		*l += r;
+	}
+	if left.values.len() < right.values.len() {
+		left.values.extend_from_slice(&right.values[left.values.len()..]);
+	}
+}
+
+///
+/// Ensures that `f` holds for all value pairs (one from each argument)
+/// for all the keys in either argument, where missing keys map to bottom.
+///
+fn for_each_pair<E,F>(left: &VecTFSpace<E>, right: &VecTFSpace<E>, f: F) -> bool
+	where
+		E: VecTFSpaceElement,
+		F: Fn(&E,&E) -> bool
+{
+	let common = left.values.len().min(right.values.len());
+	let bottom = E::bottom();
+	left.values.iter().zip(right.values.iter()).all(|(l, r)| f(l, r))
+		&& left.values[common..].iter().all(|l| f(l, &bottom))
+		&& right.values[common..].iter().all(|r| f(&bottom, r))
+}
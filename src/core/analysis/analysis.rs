@@ -1,6 +1,6 @@
 
 use crate::core::{
-	CompleteLattice, SubLattice, Worklist, Bottom
+	CompleteLattice, SubLattice, Worklist, Bottom, Widen, Narrow
 };
 use graphene::{
 	core::{
@@ -9,7 +9,7 @@ use graphene::{
 };
 use std::{
 	collections::{
-		HashMap
+		HashMap, HashSet
 	},
 	hash::Hash
 };
@@ -36,21 +36,84 @@ pub trait Analysis<G,L>
 	{
 		let mut worklist = W::initialize::<Self,_>(g);
 		
-		// Initialize all states
-		for i in g.all_vertices(){
-			if !initial_values.contains_key(&i) {
-				initial_values.insert(i, L::bottom());
+		initialize_flow_variables(g, initial_values);
+		
+		while let Some(fv) = worklist.next(){
+			let new_value = evaluate_flow_variable::<Self,_,_,_>(g, fv, initial_values);
+			let changed = initial_values.get_mut(&fv)
+				.expect("All flow variables should have been initialized above")
+				.sub_lattice_ref_mut().join_assign(&new_value);
+			if changed {
+				for v in fv_dependentants::<Self,_,_>(g, fv){
+					worklist.insert(v.0);
+				}
 			}
 		}
+	}
+	
+	///
+	/// Like [`analyze`], but for lattices with infinite ascending chains.
+	///
+	/// Solving is done in two phases. First, the flow variables are joined until stable,
+	/// except at the given widening points, where the new value is widened into the old
+	/// one instead. Afterwards, a narrowing phase recomputes all flow variables, narrowing
+	/// the values at the widening points, to regain some of the precision lost by widening.
+	///
+	/// Termination is only guaranteed if every cycle in the graph contains a widening point.
+	///
+	/// [`analyze`]: #method.analyze
+	///
+	fn analyze_widening<W>(g: &G, initial_values: &mut HashMap<G::Vertex,L>,
+						   widening_points: &HashSet<G::Vertex>)
+		where
+			W: Worklist<G>,
+			Self::Lattice: Widen + Narrow
+	{
+		initialize_flow_variables(g, initial_values);
+		
+		// The narrowing phase replaces values, so the initial values must be kept
+		// to not lose them
+		let initial: HashMap<_,Self::Lattice> = initial_values.iter()
+			.map(|(v, l)| (*v, l.sub_lattice_ref().clone())).collect();
 		
+		// Widening phase
+		let mut worklist = W::initialize::<Self,_>(g);
 		while let Some(fv) = worklist.next(){
 			let new_value = evaluate_flow_variable::<Self,_,_,_>(g, fv, initial_values);
-			if !(*initial_values[&fv].sub_lattice_ref() >= new_value)   {
-				if let Some(t) = initial_values.get_mut(&fv) {
-					*t.sub_lattice_ref_mut() += new_value;
-				}else {
-					unreachable!("All flow variables should have been initialized above")
+			let old_value = initial_values.get_mut(&fv)
+				.expect("All flow variables should have been initialized above")
+				.sub_lattice_ref_mut();
+			let changed = if widening_points.contains(&fv) {
+				let changed = !(*old_value >= new_value);
+				if changed {
+					*old_value = old_value.widen(&new_value);
+				}
+				changed
+			} else {
+				old_value.join_assign(&new_value)
+			};
+			if changed {
+				for v in fv_dependentants::<Self,_,_>(g, fv){
+					worklist.insert(v.0);
 				}
+			}
+		}
+		
+		// Narrowing phase
+		let mut worklist = W::initialize::<Self,_>(g);
+		while let Some(fv) = worklist.next(){
+			let new_value = evaluate_flow_variable::<Self,_,_,_>(g, fv, initial_values)
+				+ &initial[&fv];
+			let old_value = initial_values.get_mut(&fv)
+				.expect("All flow variables should have been initialized above")
+				.sub_lattice_ref_mut();
+			let new_value = if widening_points.contains(&fv) {
+				old_value.narrow(&new_value)
+			} else {
+				new_value
+			};
+			if *old_value != new_value {
+				*old_value = new_value;
 				for v in fv_dependentants::<Self,_,_>(g, fv){
 					worklist.insert(v.0);
 				}
@@ -61,6 +124,20 @@ pub trait Analysis<G,L>
 
 // Helper functions
 
+/// Gives all flow variables without a value the bottom value.
+fn initialize_flow_variables<G,L>(g: &G, values: &mut HashMap<G::Vertex,L>)
+	where
+		G: Graph<Directedness=Directed>,
+		G::Vertex: Hash,
+		L: Bottom
+{
+	for i in g.all_vertices(){
+		if !values.contains_key(&i) {
+			values.insert(i, L::bottom());
+		}
+	}
+}
+
 /// The flow variables that depend on the given flow variable.
 fn fv_dependentants<N,L,G>(g: &G, fv: G::Vertex) -> Vec<(G::Vertex, &G::EdgeWeight)>
 	where
@@ -0,0 +1,43 @@
+use crate::core::CompleteLattice;
+
+///
+/// Relates a [Complete Lattice] to the concrete values of type `C` it abstracts,
+/// forming a [Galois connection].
+///
+/// Each element describes a set of concrete values, given by [`gamma_contains`], while
+/// [`alpha`] gives the most precise element describing a concrete value. These must agree
+/// with the ordering of the lattice: If `e1 <= e2`, every value described by `e1` must also
+/// be described by `e2`, and `alpha(c)` must be the least element describing `c`.
+///
+/// [Complete Lattice]: http://mathworld.wolfram.com/CompleteLattice.html
+/// [Galois connection]: https://en.wikipedia.org/wiki/Galois_connection
+/// [`alpha`]: #tymethod.alpha
+/// [`gamma_contains`]: #tymethod.gamma_contains
+///
+pub trait Abstraction<C>: CompleteLattice
+{
+	///
+	/// The most precise element describing the given concrete value.
+	///
+	fn alpha(concrete: &C) -> Self;
+	
+	///
+	/// Whether the given concrete value is described by this element.
+	///
+	fn gamma_contains(&self, concrete: &C) -> bool;
+	
+	///
+	/// The most precise element describing all the given concrete values.
+	///
+	fn alpha_all<'a, I>(concrete: I) -> Self
+		where
+			I: IntoIterator<Item=&'a C>,
+			C: 'a
+	{
+		let mut result = Self::bottom();
+		for c in concrete {
+			result += Self::alpha(c);
+		}
+		result
+	}
+}
@@ -1,7 +1,7 @@
 use std::ops::{
 	Add, AddAssign
 };
-use crate::core::Bottom;
+use crate::core::{Bottom, Top};
 
 ///
 /// A [Complete Lattice].
@@ -42,4 +42,22 @@ pub trait CompleteLattice: Clone + Bottom + PartialOrd + AddAssign + Add<Output=
 	{
 		self.le(&other) || self.gt(&other)
 	}
+	
+	///
+	/// Joins the given element into this one, returning whether this element changed.
+	///
+	/// Equivalent to `!(*self >= *other)` followed by `*self += other`, but implementations
+	/// should override it to do both in a single traversal where possible.
+	///
+	fn join_assign(&mut self, other: &Self) -> bool
+	{
+		if *self >= *other {
+			false
+		} else {
+			*self += other;
+			true
+		}
+	}
 }
+
+trait_alias!(BoundedLattice: CompleteLattice, Top);
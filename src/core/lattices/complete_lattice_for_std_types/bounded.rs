@@ -0,0 +1,43 @@
+
+///
+/// A totally ordered type with a least and a greatest value.
+///
+/// Used to give [`Max`] and [`Min`] their bottom and top elements.
+///
+/// [`Max`]: struct.Max.html
+/// [`Min`]: struct.Min.html
+///
+pub trait Bounded: Clone + Ord
+{
+	const MIN: Self;
+	const MAX: Self;
+}
+
+macro_rules! impl_bounded{
+	{
+		$($t:ty),*
+	}=>{
+		$(
+			impl Bounded for $t
+			{
+				const MIN: Self = <$t>::MIN;
+				const MAX: Self = <$t>::MAX;
+			}
+		)*
+	};
+}
+
+impl_bounded!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl Bounded for char
+{
+	// `char::MIN` isn't available before Rust 1.83
+	const MIN: Self = '\0';
+	const MAX: Self = char::MAX;
+}
+
+impl Bounded for bool
+{
+	const MIN: Self = false;
+	const MAX: Self = true;
+}
@@ -0,0 +1,201 @@
+
+use crate::core::{
+	CompleteLattice, Bottom, Top, Meet, Widen, Narrow, Abstraction
+};
+use super::Bounded;
+use std::{
+	ops::{
+		Add, AddAssign
+	},
+	cmp::{
+		max, min
+	}
+};
+
+///
+/// The chain lattice over a bounded, totally ordered type, where join is the maximum.
+///
+/// Bottom is the least value of the type and top the greatest.
+///
+#[derive(Copy, Clone, Debug, PartialOrd, PartialEq, Ord, Eq, Hash)]
+pub struct Max<T>(pub T)
+	where
+		T: Bounded;
+
+///
+/// The boolean lattice where join is `||`.
+///
+pub type Or = Max<bool>;
+
+///
+/// Equivalent to `Max<u32>`.
+///
+/// This replaces the former `U32` tuple struct. Since `U32` is now a type alias and a
+/// constructor function, it can no longer be used in patterns; use `Max` instead,
+/// e.g. `let Max(v) = x;`.
+///
+pub type U32 = Max<u32>;
+
+///
+/// Creates a [`U32`](type.U32.html).
+///
+#[allow(non_snake_case)]
+pub fn U32(v: u32) -> U32
+{
+	Max(v)
+}
+
+///
+/// Equivalent to `Max<u64>`.
+///
+/// This replaces the former `U64` tuple struct. Since `U64` is now a type alias and a
+/// constructor function, it can no longer be used in patterns; use `Max` instead,
+/// e.g. `let Max(v) = x;`.
+///
+pub type U64 = Max<u64>;
+
+///
+/// Creates a [`U64`](type.U64.html).
+///
+#[allow(non_snake_case)]
+pub fn U64(v: u64) -> U64
+{
+	Max(v)
+}
+
+impl<T> CompleteLattice for Max<T>
+	where
+		T: Bounded
+{
+	fn is_bottom(&self) -> bool
+	{
+		self.0 == T::MIN
+	}
+}
+
+impl<T> Bottom for Max<T>
+	where
+		T: Bounded
+{
+	fn bottom() -> Self
+	{
+		Max(T::MIN)
+	}
+}
+
+impl<T> Top for Max<T>
+	where
+		T: Bounded
+{
+	fn top() -> Self
+	{
+		Max(T::MAX)
+	}
+	
+	fn is_top(&self) -> bool
+	{
+		self.0 == T::MAX
+	}
+}
+
+impl<T> Abstraction<T> for Max<T>
+	where
+		T: Bounded
+{
+	///
+	/// The given value, describing it and all values smaller than it.
+	///
+	fn alpha(concrete: &T) -> Self
+	{
+		Max(concrete.clone())
+	}
+	
+	fn gamma_contains(&self, concrete: &T) -> bool
+	{
+		*concrete <= self.0
+	}
+}
+
+impl<T> Add for Max<T>
+	where
+		T: Bounded
+{
+	type Output = Self;
+	fn add(self, rhs: Self) -> Self::Output
+	{
+		max(self, rhs)
+	}
+}
+
+impl<'a, T> Add<&'a Self> for Max<T>
+	where
+		T: Bounded
+{
+	type Output = Self;
+	fn add(self, rhs: &'a Self) -> Self::Output
+	{
+		if *rhs > self { rhs.clone() } else { self }
+	}
+}
+
+impl<T> AddAssign for Max<T>
+	where
+		T: Bounded
+{
+	fn add_assign(&mut self, rhs: Self)
+	{
+		if rhs > *self {
+			*self = rhs;
+		}
+	}
+}
+
+impl<'a, T> AddAssign<&'a Self> for Max<T>
+	where
+		T: Bounded
+{
+	fn add_assign(&mut self, rhs: &'a Self)
+	{
+		if *rhs > *self {
+			*self = rhs.clone();
+		}
+	}
+}
+
+impl<T> Meet for Max<T>
+	where
+		T: Bounded
+{
+	fn meet_assign(&mut self, other: &Self)
+	{
+		*self = min(self.clone(), other.clone());
+	}
+}
+
+impl<T> Widen for Max<T>
+	where
+		T: Bounded
+{
+	fn widen(&self, next: &Self) -> Self
+	{
+		if next <= self {
+			self.clone()
+		} else {
+			Self::top()
+		}
+	}
+}
+
+impl<T> Narrow for Max<T>
+	where
+		T: Bounded
+{
+	fn narrow(&self, next: &Self) -> Self
+	{
+		if self.is_top() {
+			next.clone()
+		} else {
+			self.clone()
+		}
+	}
+}
@@ -0,0 +1,186 @@
+
+use crate::core::{
+	CompleteLattice, Bottom, Top, Meet, Widen, Narrow, Abstraction
+};
+use super::Bounded;
+use std::{
+	ops::{
+		Add, AddAssign
+	},
+	cmp::Ordering,
+};
+
+///
+/// The chain lattice over a bounded, totally ordered type, where join is the minimum.
+///
+/// The order of the lattice is the reverse of the type's, so bottom is the greatest
+/// value of the type and top the least.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Min<T>(pub T)
+	where
+		T: Bounded;
+
+///
+/// The boolean lattice where join is `&&`.
+///
+pub type And = Min<bool>;
+
+impl<T> CompleteLattice for Min<T>
+	where
+		T: Bounded
+{
+	fn is_bottom(&self) -> bool
+	{
+		self.0 == T::MAX
+	}
+}
+
+impl<T> Bottom for Min<T>
+	where
+		T: Bounded
+{
+	fn bottom() -> Self
+	{
+		Min(T::MAX)
+	}
+}
+
+impl<T> Top for Min<T>
+	where
+		T: Bounded
+{
+	fn top() -> Self
+	{
+		Min(T::MIN)
+	}
+	
+	fn is_top(&self) -> bool
+	{
+		self.0 == T::MIN
+	}
+}
+
+impl<T> PartialOrd for Min<T>
+	where
+		T: Bounded
+{
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		Some(self.cmp(other))
+	}
+}
+
+impl<T> Ord for Min<T>
+	where
+		T: Bounded
+{
+	fn cmp(&self, other: &Self) -> Ordering
+	{
+		other.0.cmp(&self.0)
+	}
+}
+
+impl<T> Abstraction<T> for Min<T>
+	where
+		T: Bounded
+{
+	///
+	/// The given value, describing it and all values larger than it.
+	///
+	fn alpha(concrete: &T) -> Self
+	{
+		Min(concrete.clone())
+	}
+	
+	fn gamma_contains(&self, concrete: &T) -> bool
+	{
+		*concrete >= self.0
+	}
+}
+
+impl<T> Add for Min<T>
+	where
+		T: Bounded
+{
+	type Output = Self;
+	fn add(self, rhs: Self) -> Self::Output
+	{
+		if rhs.0 < self.0 { rhs } else { self }
+	}
+}
+
+impl<'a, T> Add<&'a Self> for Min<T>
+	where
+		T: Bounded
+{
+	type Output = Self;
+	fn add(self, rhs: &'a Self) -> Self::Output
+	{
+		if rhs.0 < self.0 { rhs.clone() } else { self }
+	}
+}
+
+impl<T> AddAssign for Min<T>
+	where
+		T: Bounded
+{
+	fn add_assign(&mut self, rhs: Self)
+	{
+		if rhs.0 < self.0 {
+			*self = rhs;
+		}
+	}
+}
+
+impl<'a, T> AddAssign<&'a Self> for Min<T>
+	where
+		T: Bounded
+{
+	fn add_assign(&mut self, rhs: &'a Self)
+	{
+		if rhs.0 < self.0 {
+			*self = rhs.clone();
+		}
+	}
+}
+
+impl<T> Meet for Min<T>
+	where
+		T: Bounded
+{
+	fn meet_assign(&mut self, other: &Self)
+	{
+		if other.0 > self.0 {
+			*self = other.clone();
+		}
+	}
+}
+
+impl<T> Widen for Min<T>
+	where
+		T: Bounded
+{
+	fn widen(&self, next: &Self) -> Self
+	{
+		if next <= self {
+			self.clone()
+		} else {
+			Self::top()
+		}
+	}
+}
+
+impl<T> Narrow for Min<T>
+	where
+		T: Bounded
+{
+	fn narrow(&self, next: &Self) -> Self
+	{
+		if self.is_top() {
+			next.clone()
+		} else {
+			self.clone()
+		}
+	}
+}
@@ -1,8 +1,9 @@
-
-mod u_32;
-mod u_64;
+mod bounded;
+mod max;
+mod min;
 
 pub use self::{
-	u_32::*,
-	u_64::*,
-};
\ No newline at end of file
+	bounded::*,
+	max::*,
+	min::*,
+};
@@ -0,0 +1,161 @@
+
+use crate::core::{
+	CompleteLattice, Bottom, Top, Meet
+};
+use std::{
+	ops::{
+		Add, AddAssign
+	},
+	cmp::Ordering,
+};
+
+///
+/// The order-dual of a [Complete Lattice].
+///
+/// Reverses the ordering of the wrapped lattice, such that its join is the wrapped meet,
+/// its bottom is the wrapped top and vice versa.
+/// This allows greatest fixed points to be computed using the least fixed point
+/// solver in [`Analysis`].
+///
+/// The wrapped element is deliberately not a [`SubLattice`], as an analysis using the
+/// wrapped lattice would compute the least fixed point instead. It is accessible through
+/// [`inner`] and [`inner_mut`].
+///
+/// [Complete Lattice]: http://mathworld.wolfram.com/CompleteLattice.html
+/// [`Analysis`]: trait.Analysis.html
+/// [`SubLattice`]: trait.SubLattice.html
+/// [`inner`]: #method.inner
+/// [`inner_mut`]: #method.inner_mut
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dual<L>(pub L)
+	where
+		L: CompleteLattice + Top + Meet;
+
+impl<L> Dual<L>
+	where
+		L: CompleteLattice + Top + Meet
+{
+	///
+	/// The wrapped element, ordered as in the wrapped lattice.
+	///
+	pub fn inner(&self) -> &L
+	{
+		&self.0
+	}
+	
+	///
+	/// The wrapped element, ordered as in the wrapped lattice.
+	///
+	/// Note that joining into it uses the join of `L`, not the join of the dual.
+	///
+	pub fn inner_mut(&mut self) -> &mut L
+	{
+		&mut self.0
+	}
+	
+	///
+	/// Unwraps the element.
+	///
+	pub fn into_inner(self) -> L
+	{
+		self.0
+	}
+}
+
+impl<L> CompleteLattice for Dual<L>
+	where
+		L: CompleteLattice + Top + Meet
+{
+	fn is_bottom(&self) -> bool
+	{
+		self.0.is_top()
+	}
+}
+
+impl<L> Bottom for Dual<L>
+	where
+		L: CompleteLattice + Top + Meet
+{
+	fn bottom() -> Self
+	{
+		Dual(L::top())
+	}
+}
+
+impl<L> Top for Dual<L>
+	where
+		L: CompleteLattice + Top + Meet
+{
+	fn top() -> Self
+	{
+		Dual(L::bottom())
+	}
+	
+	fn is_top(&self) -> bool
+	{
+		self.0.is_bottom()
+	}
+}
+
+impl<L> Meet for Dual<L>
+	where
+		L: CompleteLattice + Top + Meet
+{
+	fn meet_assign(&mut self, other: &Self)
+	{
+		self.0 += &other.0;
+	}
+}
+
+impl<L> PartialOrd for Dual<L>
+	where
+		L: CompleteLattice + Top + Meet
+{
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		other.0.partial_cmp(&self.0)
+	}
+}
+
+impl<L> Add for Dual<L>
+	where
+		L: CompleteLattice + Top + Meet
+{
+	type Output = Self;
+	fn add(self, rhs: Self) -> Self::Output
+	{
+		Dual(self.0.meet(&rhs.0))
+	}
+}
+
+impl<'a, L> Add<&'a Self> for Dual<L>
+	where
+		L: CompleteLattice + Top + Meet
+{
+	type Output = Self;
+	fn add(self, rhs: &'a Self) -> Self::Output
+	{
+		Dual(self.0.meet(&rhs.0))
+	}
+}
+
+impl<L> AddAssign for Dual<L>
+	where
+		L: CompleteLattice + Top + Meet
+{
+	fn add_assign(&mut self, rhs: Self)
+	{
+		self.0.meet_assign(&rhs.0);
+	}
+}
+
+impl<'a, L> AddAssign<&'a Self> for Dual<L>
+	where
+		L: CompleteLattice + Top + Meet
+{
+	fn add_assign(&mut self, rhs: &'a Self)
+	{
+		self.0.meet_assign(&rhs.0);
+	}
+}
@@ -0,0 +1,16 @@
+
+///
+/// A type with a finite number of values.
+///
+/// Used to declare the universe of items a lattice is built over, such that
+/// lattices like [Power Set]s and Total Function Spaces have a representable top element.
+///
+/// [Power Set]: http://mathworld.wolfram.com/PowerSet.html
+///
+pub trait Finite: Sized
+{
+	///
+	/// Returns every value of the type, each exactly once.
+	///
+	fn universe() -> Vec<Self>;
+}
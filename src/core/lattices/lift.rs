@@ -0,0 +1,365 @@
+use crate::core::{
+	CompleteLattice, Bottom, Top, Meet, SubLattice, PowerSet, TFSpace, TFSpaceKey, TFSpaceElement
+};
+use std::{
+	borrow::Cow,
+	ops::{
+		Add, AddAssign, Index, IndexMut
+	},
+	cmp::Ordering,
+};
+
+///
+/// A [Complete Lattice] with a new bottom element added below the wrapped lattice.
+///
+/// When used as the lattice of an [`Analysis`], the new bottom marks program points
+/// that are unreachable, while the bottom of the wrapped lattice marks reachable points
+/// where no facts hold. Since the solver initializes all flow variables to bottom,
+/// only the points reachable from an initial value given with [`reachable`] become
+/// reachable, as long as the transfer functions keep unreachable values unreachable,
+/// e.g. by using [`map`].
+///
+/// An element whose wrapped value isn't bottom is always reachable. Therefore, the wrapped
+/// value can also be accessed and joined into through [`SubLattice`], [`PowerSet`] or
+/// [`TFSpace`] methods, e.g. by an analysis whose lattice is the wrapped one, without making
+/// unreachable elements reachable. However, such an analysis can't distinguish unreachable
+/// points from reachable points where the wrapped value is bottom.
+///
+/// [Complete Lattice]: http://mathworld.wolfram.com/CompleteLattice.html
+/// [`Analysis`]: trait.Analysis.html
+/// [`reachable`]: #method.reachable
+/// [`map`]: #method.map
+/// [`SubLattice`]: trait.SubLattice.html
+/// [`PowerSet`]: trait.PowerSet.html
+/// [`TFSpace`]: trait.TFSpace.html
+///
+#[derive(Clone, Debug)]
+pub struct Lift<L>
+	where
+		L: CompleteLattice
+{
+	/// Whether the element is reachable even though the wrapped value may be bottom.
+	reachable: bool,
+	value: L,
+}
+
+impl<L> Lift<L>
+	where
+		L: CompleteLattice
+{
+	///
+	/// The new bottom element.
+	///
+	pub fn unreachable() -> Self
+	{
+		Self{reachable: false, value: L::bottom()}
+	}
+	
+	///
+	/// Lifts the given element of the wrapped lattice.
+	///
+	pub fn reachable(value: L) -> Self
+	{
+		Self{reachable: true, value}
+	}
+	
+	///
+	/// Whether this isn't the new bottom element.
+	///
+	/// Elements wrapping a value other than bottom are always reachable.
+	///
+	pub fn is_reachable(&self) -> bool
+	{
+		self.reachable || !self.value.is_bottom()
+	}
+	
+	///
+	/// The wrapped value, if reachable.
+	///
+	pub fn value(&self) -> Option<&L>
+	{
+		if self.is_reachable() {
+			Some(&self.value)
+		} else {
+			None
+		}
+	}
+	
+	///
+	/// Applies the given function to the wrapped value, if reachable.
+	/// Otherwise, the result is also unreachable.
+	///
+	pub fn map<F>(&self, f: F) -> Self
+		where
+			F: FnOnce(&L) -> L
+	{
+		if self.is_reachable() {
+			Self::reachable(f(&self.value))
+		} else {
+			Self::unreachable()
+		}
+	}
+	
+	/// Keeps a reachable element reachable, even if its wrapped value becomes bottom.
+	fn pin_reachability(&mut self)
+	{
+		self.reachable = self.is_reachable();
+	}
+}
+
+impl<L> CompleteLattice for Lift<L>
+	where
+		L: CompleteLattice
+{
+	fn is_bottom(&self) -> bool
+	{
+		!self.is_reachable()
+	}
+	
+	fn join_assign(&mut self, other: &Self) -> bool
+	{
+		if !other.is_reachable() {
+			false
+		} else if !self.is_reachable() {
+			*self = other.clone();
+			true
+		} else {
+			self.value.join_assign(&other.value)
+		}
+	}
+}
+
+impl<L> Bottom for Lift<L>
+	where
+		L: CompleteLattice
+{
+	fn bottom() -> Self
+	{
+		Self::unreachable()
+	}
+}
+
+impl<L> Top for Lift<L>
+	where
+		L: CompleteLattice + Top
+{
+	fn top() -> Self
+	{
+		Self::reachable(L::top())
+	}
+	
+	fn is_top(&self) -> bool
+	{
+		self.is_reachable() && self.value.is_top()
+	}
+}
+
+impl<L> Meet for Lift<L>
+	where
+		L: CompleteLattice + Meet
+{
+	fn meet_assign(&mut self, other: &Self)
+	{
+		if other.is_reachable() {
+			self.pin_reachability();
+			self.value.meet_assign(&other.value);
+		} else {
+			*self = Self::unreachable();
+		}
+	}
+}
+
+impl<L> PartialOrd for Lift<L>
+	where
+		L: CompleteLattice
+{
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		match (self.is_reachable(), other.is_reachable()) {
+			(true, true) => self.value.partial_cmp(&other.value),
+			(true, false) => Some(Ordering::Greater),
+			(false, true) => Some(Ordering::Less),
+			(false, false) => Some(Ordering::Equal),
+		}
+	}
+}
+
+impl<L> PartialEq for Lift<L>
+	where
+		L: CompleteLattice
+{
+	fn eq(&self, other: &Self) -> bool
+	{
+		self.is_reachable() == other.is_reachable() && (!self.is_reachable() || self.value == other.value)
+	}
+}
+
+impl<L> Add for Lift<L>
+	where
+		L: CompleteLattice
+{
+	type Output = Self;
+	fn add(mut self, rhs: Self) -> Self::Output
+	{
+		self += rhs;
+		self
+	}
+}
+
+impl<'a, L> Add<&'a Self> for Lift<L>
+	where
+		L: CompleteLattice
+{
+	type Output = Self;
+	fn add(mut self, rhs: &'a Self) -> Self::Output
+	{
+		self += rhs;
+		self
+	}
+}
+
+impl<L> AddAssign for Lift<L>
+	where
+		L: CompleteLattice
+{
+	fn add_assign(&mut self, rhs: Self)
+	{
+		if rhs.is_reachable() {
+			self.reachable = true;
+			self.value += rhs.value;
+		}
+	}
+}
+
+impl<'a, L> AddAssign<&'a Self> for Lift<L>
+	where
+		L: CompleteLattice
+{
+	fn add_assign(&mut self, rhs: &'a Self)
+	{
+		if rhs.is_reachable() {
+			self.reachable = true;
+			self.value += &rhs.value;
+		}
+	}
+}
+
+impl<L> SubLattice<L> for Lift<L>
+	where
+		L: CompleteLattice
+{
+	fn sub_lattice(self) -> L
+	{
+		self.value
+	}
+	
+	fn sub_lattice_ref(&self) -> &L
+	{
+		&self.value
+	}
+	
+	fn sub_lattice_ref_mut(&mut self) -> &mut L
+	{
+		self.pin_reachability();
+		&mut self.value
+	}
+}
+
+impl<L> PowerSet for Lift<L>
+	where
+		L: PowerSet
+{
+	type Item = L::Item;
+	type All = L::All;
+	type Iter<'a> = L::Iter<'a> where Self: 'a;
+	
+	fn singleton(s: Self::Item) -> Self
+	{
+		Self::reachable(L::singleton(s))
+	}
+	
+	fn all(&self) -> Self::All
+	{
+		self.value.all()
+	}
+	
+	fn iter(&self) -> Self::Iter<'_>
+	{
+		self.value.iter()
+	}
+	
+	fn contains(&self, item: &Self::Item) -> bool
+	{
+		self.value.contains(item)
+	}
+	
+	fn len(&self) -> usize
+	{
+		self.value.len()
+	}
+	
+	fn insert(&mut self, item: Self::Item) -> bool
+	{
+		self.pin_reachability();
+		self.value.insert(item)
+	}
+	
+	fn remove(&mut self, item: &Self::Item) -> bool
+	{
+		self.pin_reachability();
+		self.value.remove(item)
+	}
+	
+	fn from_iter<F>(i: F) -> Self
+		where F: IntoIterator<Item=Self::Item>
+	{
+		Self::reachable(L::from_iter(i))
+	}
+}
+
+impl<K,E,L> TFSpace<K,E> for Lift<L>
+	where
+		K: TFSpaceKey,
+		E: TFSpaceElement,
+		L: TFSpace<K,E>
+{
+	type Keys = L::Keys;
+	
+	fn keys(&self) -> Self::Keys
+	{
+		self.value.keys()
+	}
+	
+	fn has_key(&self, k: K) -> bool
+	{
+		self.value.has_key(k)
+	}
+	
+	fn get(&self, k: K) -> Cow<'_, E>
+	{
+		self.value.get(k)
+	}
+}
+
+impl<K,L> Index<K> for Lift<L>
+	where
+		L: CompleteLattice + Index<K>
+{
+	type Output = <L as Index<K>>::Output;
+	
+	fn index(&self, index: K) -> &Self::Output
+	{
+		&self.value[index]
+	}
+}
+
+impl<K,L> IndexMut<K> for Lift<L>
+	where
+		L: CompleteLattice + IndexMut<K>
+{
+	fn index_mut(&mut self, index: K) -> &mut Self::Output
+	{
+		self.pin_reachability();
+		&mut self.value[index]
+	}
+}
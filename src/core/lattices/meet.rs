@@ -0,0 +1,30 @@
+
+use crate::core::CompleteLattice;
+
+///
+/// The meet operation of a [Complete Lattice].
+///
+/// Where [`Add`] gives the least upper bound of two elements, [`meet`] gives
+/// the greatest lower bound. I.e. if `e3 = e1.meet(&e2)` then `e3` is the largest
+/// element in the lattice where `e3 <= e1 && e3 <= e2`.
+///
+/// [Complete Lattice]: http://mathworld.wolfram.com/CompleteLattice.html
+/// [`Add`]: https://doc.rust-lang.org/std/ops/trait.Add.html
+/// [`meet`]: #method.meet
+///
+pub trait Meet: CompleteLattice
+{
+	///
+	/// Replaces this instance with the greatest lower bound of itself and the given.
+	///
+	fn meet_assign(&mut self, other: &Self);
+	
+	///
+	/// Returns the greatest lower bound of this instance and the given.
+	///
+	fn meet(mut self, other: &Self) -> Self
+	{
+		self.meet_assign(other);
+		self
+	}
+}
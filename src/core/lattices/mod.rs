@@ -11,6 +11,16 @@ mod tf_space;
 mod complete_lattice_for_std_types;
 mod sub_lattice;
 mod bottom;
+mod top;
+mod finite;
+mod meet;
+mod widening;
+mod dual;
+mod lift;
+mod sum;
+mod reduced_product;
+mod abstraction;
+mod product;
 
 pub use self::{
 	complete_lattice::*,
@@ -19,4 +29,13 @@ pub use self::{
 	sub_lattice::*,
 	complete_lattice_for_std_types::*,
 	bottom::*,
+	top::*,
+	finite::*,
+	meet::*,
+	widening::*,
+	dual::*,
+	lift::*,
+	sum::*,
+	reduced_product::*,
+	abstraction::*,
 };
@@ -1,12 +1,27 @@
 
 use crate::core::{CompleteLattice};
+use std::borrow::Cow;
 
 trait_alias!{PowerSetItem: Clone, Eq}
 
 ///
 /// A [Power Set] [Complete Lattice] over a set of items.
 ///
+/// The default implementations of [`insert`] and [`remove`] assume that bottom is the
+/// empty set and that joining is union, and should be overridden otherwise.
 ///
+/// [`Iter`] is a generic associated type, which requires Rust 1.65 or later.
+///
+/// Note that [`Iter`] and [`iter`] are required and have no defaults, which breaks
+/// implementations written before they were added. Implementations that don't store their
+/// items can use e.g. `std::vec::IntoIter<Cow<'a, Self::Item>>` as [`Iter`], and collect
+/// the items of [`all`] as `Cow::Owned`.
+///
+/// [`insert`]: #method.insert
+/// [`remove`]: #method.remove
+/// [`Iter`]: #associatedtype.Iter
+/// [`iter`]: #tymethod.iter
+/// [`all`]: #tymethod.all
 /// [Complete Lattice]: http://mathworld.wolfram.com/CompleteLattice.html
 /// [Power Set]: http://mathworld.wolfram.com/PowerSet.html
 ///
@@ -24,6 +39,17 @@ pub trait PowerSet: CompleteLattice
 	///
 	type All: IntoIterator<Item=Self::Item>;
 	
+	///
+	/// The type returned by [`iter`].
+	///
+	/// Items are borrowed from the set where possible, but implementations that don't
+	/// store their items directly may produce owned ones.
+	///
+	/// [`iter`]: #tymethod.iter
+	///
+	type Iter<'a>: Iterator<Item=Cow<'a, Self::Item>>
+		where Self: 'a;
+	
 	///
 	/// Creates an element of the [Power Set](http://mathworld.wolfram.com/PowerSet.html)
 	/// which only comprises the given item.
@@ -36,6 +62,71 @@ pub trait PowerSet: CompleteLattice
 	///
 	fn all(&self) -> Self::All;
 	
+	///
+	/// Iterates over the items in the set without cloning it.
+	///
+	fn iter(&self) -> Self::Iter<'_>;
+	
+	///
+	/// Whether the given item is in the set.
+	///
+	fn contains(&self, item: &Self::Item) -> bool
+	{
+		self.iter().any(|i| *i == *item)
+	}
+	
+	///
+	/// The number of items in the set.
+	///
+	fn len(&self) -> usize
+	{
+		self.iter().count()
+	}
+	
+	///
+	/// Whether the set has no items.
+	///
+	/// Note that this is not necessarily the same as being bottom,
+	/// as that depends on the ordering of the lattice.
+	///
+	fn is_empty(&self) -> bool
+	{
+		self.len() == 0
+	}
+	
+	///
+	/// Adds the given item to the set.
+	///
+	/// Returns whether the item was not already in the set.
+	///
+	fn insert(&mut self, item: Self::Item) -> bool
+	{
+		if self.contains(&item) {
+			false
+		} else {
+			*self += Self::singleton(item);
+			true
+		}
+	}
+	
+	///
+	/// Removes the given item from the set.
+	///
+	/// Returns whether the item was in the set.
+	///
+	fn remove(&mut self, item: &Self::Item) -> bool
+	{
+		if self.contains(item) {
+			*self = Self::from_iter(self.iter()
+				.filter(|i| **i != *item)
+				.map(|i| i.into_owned())
+				.collect::<Vec<_>>());
+			true
+		} else {
+			false
+		}
+	}
+	
 	///
 	/// li
 	///
@@ -0,0 +1,158 @@
+
+///
+/// Declares a tuple struct that is the componentwise product of the lattices it contains.
+///
+/// Rust tuples already have a lexicographic [`PartialOrd`] and can't implement the
+/// operators of a [`CompleteLattice`], so products must be declared as new types.
+/// The declared struct derives `Clone` and `PartialEq` and implements:
+///
+/// * [`CompleteLattice`]: It is bottom iff all its components are bottom.
+/// * [`Bottom`]: All components are bottom.
+/// * [`PartialOrd`]: `p1 <= p2` iff `p1.i <= p2.i` for all components `i`.
+/// * [`Add`] and [`AddAssign`]: Componentwise join.
+/// * [`SubLattice`]: For each of the component types. Therefore, no two components
+///   may have the same type.
+///
+/// ```
+/// # #[macro_use] extern crate progysis;
+/// # use progysis::core::{U32, U64, Bottom, SubLattice};
+/// product_lattice!{
+///     #[derive(Copy, Debug)]
+///     pub struct D32(pub U64, pub U32);
+/// }
+///
+/// # fn main() {
+/// let d = D32(U64(1), U32(2)) + D32(U64(3), U32(0));
+/// assert_eq!(d, D32(U64(3), U32(2)));
+/// let u: &U32 = d.sub_lattice_ref();
+/// assert_eq!(*u, U32(2));
+/// # }
+/// ```
+///
+/// [`CompleteLattice`]: core/trait.CompleteLattice.html
+/// [`Bottom`]: core/trait.Bottom.html
+/// [`SubLattice`]: core/trait.SubLattice.html
+/// [`PartialOrd`]: https://doc.rust-lang.org/std/cmp/trait.PartialOrd.html
+/// [`Add`]: https://doc.rust-lang.org/std/ops/trait.Add.html
+/// [`AddAssign`]: https://doc.rust-lang.org/std/ops/trait.AddAssign.html
+///
+#[macro_export]
+macro_rules! product_lattice{
+	{
+		$(#[$attr:meta])*
+		$vis:vis struct $name:ident ( $($field_vis:vis $field:ty),+ $(,)* );
+	}=>{
+		$(#[$attr])*
+		#[derive(Clone, PartialEq)]
+		$vis struct $name ( $($field_vis $field),+ );
+		
+		product_lattice!{@zip $name [] [$($field),+] [0 1 2 3 4 5 6 7 8 9 10 11]}
+	};
+	// Pairs each field type with its index
+	{
+		@zip $name:ident [$($done:tt)*] [$field:ty $(, $rest:ty)*] [$idx:tt $($idxs:tt)*]
+	}=>{
+		product_lattice!{@zip $name [$($done)* $field => $idx,] [$($rest),*] [$($idxs)*]}
+	};
+	{
+		@zip $name:ident [$($done:tt)*] [] [$($idxs:tt)*]
+	}=>{
+		product_lattice!{@impl $name [$($done)*]}
+	};
+	{
+		@impl $name:ident [$($field:ty => $idx:tt,)+]
+	}=>{
+		impl $crate::core::CompleteLattice for $name
+		{
+			fn is_bottom(&self) -> bool
+			{
+				true $(&& self.$idx.is_bottom())+
+			}
+			
+			fn join_assign(&mut self, other: &Self) -> bool
+			{
+				// Not short-circuiting, as every field must be joined
+				false $(| self.$idx.join_assign(&other.$idx))+
+			}
+		}
+		
+		impl $crate::core::Bottom for $name
+		{
+			fn bottom() -> Self
+			{
+				$name($(<$field as $crate::core::Bottom>::bottom()),+)
+			}
+		}
+		
+		impl ::std::cmp::PartialOrd for $name
+		{
+			fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering>
+			{
+				let le = true $(&& self.$idx <= other.$idx)+;
+				let ge = true $(&& self.$idx >= other.$idx)+;
+				match (le, ge) {
+					(true, true) => Some(::std::cmp::Ordering::Equal),
+					(true, false) => Some(::std::cmp::Ordering::Less),
+					(false, true) => Some(::std::cmp::Ordering::Greater),
+					(false, false) => None,
+				}
+			}
+		}
+		
+		impl ::std::ops::Add for $name
+		{
+			type Output = Self;
+			fn add(mut self, rhs: Self) -> Self::Output
+			{
+				$(self.$idx += rhs.$idx;)+
+				self
+			}
+		}
+		
+		impl<'a> ::std::ops::Add<&'a Self> for $name
+		{
+			type Output = Self;
+			fn add(mut self, rhs: &'a Self) -> Self::Output
+			{
+				$(self.$idx += &rhs.$idx;)+
+				self
+			}
+		}
+		
+		impl ::std::ops::AddAssign for $name
+		{
+			fn add_assign(&mut self, rhs: Self)
+			{
+				$(self.$idx += rhs.$idx;)+
+			}
+		}
+		
+		impl<'a> ::std::ops::AddAssign<&'a Self> for $name
+		{
+			fn add_assign(&mut self, rhs: &'a Self)
+			{
+				$(self.$idx += &rhs.$idx;)+
+			}
+		}
+		
+		$(
+			impl $crate::core::SubLattice<$field> for $name
+			{
+				fn sub_lattice(self) -> $field
+				{
+					self.$idx
+				}
+				
+				fn sub_lattice_ref(&self) -> &$field
+				{
+					&self.$idx
+				}
+				
+				fn sub_lattice_ref_mut(&mut self) -> &mut $field
+				{
+					&mut self.$idx
+				}
+			}
+		)+
+	};
+}
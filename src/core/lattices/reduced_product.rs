@@ -0,0 +1,314 @@
+use crate::core::{
+	CompleteLattice, Bottom, Top, Meet, Widen, Narrow
+};
+use std::{
+	ops::{
+		Add, AddAssign
+	},
+	cmp::Ordering,
+	fmt::{
+		Debug, Formatter, Result as FmtResult
+	},
+	marker::PhantomData,
+};
+
+///
+/// A reduction between two [Complete Lattice]s, used by [`ReducedProduct`].
+///
+/// `reduce` must refine each of the given elements using the information in the other,
+/// without changing what the pair describes. I.e. the result must be smaller than or
+/// equal to the given pair, and reducing twice must give the same result as reducing once.
+///
+/// If either element is bottom, nothing can be described by the pair, so the reduction
+/// should make both elements bottom.
+///
+/// [Complete Lattice]: http://mathworld.wolfram.com/CompleteLattice.html
+/// [`ReducedProduct`]: struct.ReducedProduct.html
+///
+pub trait Reduction<A, B>
+	where
+		A: CompleteLattice,
+		B: CompleteLattice
+{
+	///
+	/// Refines the given elements using each other.
+	///
+	fn reduce(a: &mut A, b: &mut B);
+}
+
+///
+/// The product of two [Complete Lattice]s, which are refined using each other by the
+/// reduction `R`.
+///
+/// Elements are ordered and joined componentwise, after which the reduction is applied.
+/// Since elements can only be created through [`new`], which also reduces, the results
+/// of transfer functions are reduced too. This way, the combination is more precise than
+/// analyzing each of the lattices separately.
+///
+/// Widening does not reduce, as that could prevent the widened sequence from stabilizing.
+///
+/// [Complete Lattice]: http://mathworld.wolfram.com/CompleteLattice.html
+/// [`new`]: #method.new
+///
+pub struct ReducedProduct<A, B, R>
+	where
+		A: CompleteLattice,
+		B: CompleteLattice,
+		R: Reduction<A, B>
+{
+	first: A,
+	second: B,
+	reduction: PhantomData<R>,
+}
+
+impl<A, B, R> ReducedProduct<A, B, R>
+	where
+		A: CompleteLattice,
+		B: CompleteLattice,
+		R: Reduction<A, B>
+{
+	///
+	/// The reduced pair of the given elements.
+	///
+	pub fn new(first: A, second: B) -> Self
+	{
+		let mut result = Self::unreduced(first, second);
+		result.reduce();
+		result
+	}
+	
+	///
+	/// The element of the first lattice.
+	///
+	pub fn first(&self) -> &A
+	{
+		&self.first
+	}
+	
+	///
+	/// The element of the second lattice.
+	///
+	pub fn second(&self) -> &B
+	{
+		&self.second
+	}
+	
+	///
+	/// The elements of the first and second lattice.
+	///
+	/// Since they can only be accessed immutably otherwise, this is the way to modify
+	/// them, after which [`new`] reduces them again.
+	///
+	/// [`new`]: #method.new
+	///
+	pub fn into_parts(self) -> (A, B)
+	{
+		(self.first, self.second)
+	}
+	
+	fn unreduced(first: A, second: B) -> Self
+	{
+		Self{first, second, reduction: PhantomData}
+	}
+	
+	fn reduce(&mut self)
+	{
+		R::reduce(&mut self.first, &mut self.second);
+	}
+}
+
+impl<A, B, R> Clone for ReducedProduct<A, B, R>
+	where
+		A: CompleteLattice,
+		B: CompleteLattice,
+		R: Reduction<A, B>
+{
+	fn clone(&self) -> Self
+	{
+		Self::unreduced(self.first.clone(), self.second.clone())
+	}
+}
+
+impl<A, B, R> Debug for ReducedProduct<A, B, R>
+	where
+		A: CompleteLattice + Debug,
+		B: CompleteLattice + Debug,
+		R: Reduction<A, B>
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		f.debug_tuple("ReducedProduct").field(&self.first).field(&self.second).finish()
+	}
+}
+
+impl<A, B, R> CompleteLattice for ReducedProduct<A, B, R>
+	where
+		A: CompleteLattice,
+		B: CompleteLattice,
+		R: Reduction<A, B>
+{
+	fn is_bottom(&self) -> bool
+	{
+		self.first.is_bottom() && self.second.is_bottom()
+	}
+	
+	fn join_assign(&mut self, other: &Self) -> bool
+	{
+		// The reduction may take the joined components back to the old value,
+		// so only the final result can tell whether anything changed
+		let old = self.clone();
+		*self += other;
+		*self != old
+	}
+}
+
+impl<A, B, R> Bottom for ReducedProduct<A, B, R>
+	where
+		A: CompleteLattice,
+		B: CompleteLattice,
+		R: Reduction<A, B>
+{
+	fn bottom() -> Self
+	{
+		Self::unreduced(A::bottom(), B::bottom())
+	}
+}
+
+impl<A, B, R> Top for ReducedProduct<A, B, R>
+	where
+		A: CompleteLattice + Top,
+		B: CompleteLattice + Top,
+		R: Reduction<A, B>
+{
+	fn top() -> Self
+	{
+		Self::new(A::top(), B::top())
+	}
+	
+	fn is_top(&self) -> bool
+	{
+		self.first.is_top() && self.second.is_top()
+	}
+}
+
+impl<A, B, R> Meet for ReducedProduct<A, B, R>
+	where
+		A: CompleteLattice + Meet,
+		B: CompleteLattice + Meet,
+		R: Reduction<A, B>
+{
+	fn meet_assign(&mut self, other: &Self)
+	{
+		self.first.meet_assign(&other.first);
+		self.second.meet_assign(&other.second);
+		self.reduce();
+	}
+}
+
+impl<A, B, R> Widen for ReducedProduct<A, B, R>
+	where
+		A: CompleteLattice + Widen,
+		B: CompleteLattice + Widen,
+		R: Reduction<A, B>
+{
+	fn widen(&self, next: &Self) -> Self
+	{
+		Self::unreduced(self.first.widen(&next.first), self.second.widen(&next.second))
+	}
+}
+
+impl<A, B, R> Narrow for ReducedProduct<A, B, R>
+	where
+		A: CompleteLattice + Narrow,
+		B: CompleteLattice + Narrow,
+		R: Reduction<A, B>
+{
+	fn narrow(&self, next: &Self) -> Self
+	{
+		Self::new(self.first.narrow(&next.first), self.second.narrow(&next.second))
+	}
+}
+
+impl<A, B, R> PartialOrd for ReducedProduct<A, B, R>
+	where
+		A: CompleteLattice,
+		B: CompleteLattice,
+		R: Reduction<A, B>
+{
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		match (self.first.partial_cmp(&other.first), self.second.partial_cmp(&other.second)) {
+			(Some(Ordering::Equal), o) | (o, Some(Ordering::Equal)) => o,
+			(Some(o1), Some(o2)) if o1 == o2 => Some(o1),
+			_ => None,
+		}
+	}
+}
+
+impl<A, B, R> PartialEq for ReducedProduct<A, B, R>
+	where
+		A: CompleteLattice,
+		B: CompleteLattice,
+		R: Reduction<A, B>
+{
+	fn eq(&self, other: &Self) -> bool
+	{
+		self.first == other.first && self.second == other.second
+	}
+}
+
+impl<A, B, R> Add for ReducedProduct<A, B, R>
+	where
+		A: CompleteLattice,
+		B: CompleteLattice,
+		R: Reduction<A, B>
+{
+	type Output = Self;
+	fn add(mut self, rhs: Self) -> Self::Output
+	{
+		self += rhs;
+		self
+	}
+}
+
+impl<'a, A, B, R> Add<&'a Self> for ReducedProduct<A, B, R>
+	where
+		A: CompleteLattice,
+		B: CompleteLattice,
+		R: Reduction<A, B>
+{
+	type Output = Self;
+	fn add(mut self, rhs: &'a Self) -> Self::Output
+	{
+		self += rhs;
+		self
+	}
+}
+
+impl<A, B, R> AddAssign for ReducedProduct<A, B, R>
+	where
+		A: CompleteLattice,
+		B: CompleteLattice,
+		R: Reduction<A, B>
+{
+	fn add_assign(&mut self, rhs: Self)
+	{
+		self.first += rhs.first;
+		self.second += rhs.second;
+		self.reduce();
+	}
+}
+
+impl<'a, A, B, R> AddAssign<&'a Self> for ReducedProduct<A, B, R>
+	where
+		A: CompleteLattice,
+		B: CompleteLattice,
+		R: Reduction<A, B>
+{
+	fn add_assign(&mut self, rhs: &'a Self)
+	{
+		self.first += &rhs.first;
+		self.second += &rhs.second;
+		self.reduce();
+	}
+}
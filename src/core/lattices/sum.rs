@@ -0,0 +1,279 @@
+use crate::core::{
+	CompleteLattice, Bottom, Top, Meet
+};
+use std::{
+	ops::{
+		Add, AddAssign
+	},
+	cmp::Ordering,
+};
+
+///
+/// The separated sum of two [Complete Lattice]s.
+///
+/// An element is either from the left or the right lattice, ordered as in that lattice,
+/// while elements from different sides are incomparable. The two lattices share their
+/// bottom, and a new top is added above both, such that joining elements from
+/// different sides results in `Top`.
+///
+/// `Left` and `Right` elements that are bottom in their own lattice are equal to `Bottom`,
+/// and are treated as such: the accessors don't return them, and joins and meets
+/// give `Bottom` instead.
+///
+/// [Complete Lattice]: http://mathworld.wolfram.com/CompleteLattice.html
+///
+#[derive(Clone, Debug)]
+pub enum Sum<A, B>
+	where
+		A: CompleteLattice,
+		B: CompleteLattice
+{
+	Bottom,
+	Left(A),
+	Right(B),
+	Top,
+}
+
+impl<A, B> Sum<A, B>
+	where
+		A: CompleteLattice,
+		B: CompleteLattice
+{
+	///
+	/// The left element, if this is one and it isn't bottom.
+	///
+	pub fn left(self) -> Option<A>
+	{
+		match self {
+			Sum::Left(a) if !a.is_bottom() => Some(a),
+			_ => None,
+		}
+	}
+	
+	///
+	/// A reference to the left element, if this is one and it isn't bottom.
+	///
+	pub fn left_ref(&self) -> Option<&A>
+	{
+		match self {
+			Sum::Left(a) if !a.is_bottom() => Some(a),
+			_ => None,
+		}
+	}
+	
+	///
+	/// A mutable reference to the left element, if this is one and it isn't bottom.
+	///
+	pub fn left_ref_mut(&mut self) -> Option<&mut A>
+	{
+		match self {
+			Sum::Left(a) if !a.is_bottom() => Some(a),
+			_ => None,
+		}
+	}
+	
+	///
+	/// The right element, if this is one and it isn't bottom.
+	///
+	pub fn right(self) -> Option<B>
+	{
+		match self {
+			Sum::Right(b) if !b.is_bottom() => Some(b),
+			_ => None,
+		}
+	}
+	
+	///
+	/// A reference to the right element, if this is one and it isn't bottom.
+	///
+	pub fn right_ref(&self) -> Option<&B>
+	{
+		match self {
+			Sum::Right(b) if !b.is_bottom() => Some(b),
+			_ => None,
+		}
+	}
+	
+	///
+	/// A mutable reference to the right element, if this is one and it isn't bottom.
+	///
+	pub fn right_ref_mut(&mut self) -> Option<&mut B>
+	{
+		match self {
+			Sum::Right(b) if !b.is_bottom() => Some(b),
+			_ => None,
+		}
+	}
+	
+	///
+	/// Replaces `Left` and `Right` elements that are bottom with `Bottom`.
+	///
+	fn normalize(&mut self)
+	{
+		if self.is_bottom() {
+			*self = Sum::Bottom;
+		}
+	}
+}
+
+impl<A, B> CompleteLattice for Sum<A, B>
+	where
+		A: CompleteLattice,
+		B: CompleteLattice
+{
+	fn is_bottom(&self) -> bool
+	{
+		match self {
+			Sum::Bottom => true,
+			Sum::Left(a) => a.is_bottom(),
+			Sum::Right(b) => b.is_bottom(),
+			Sum::Top => false,
+		}
+	}
+}
+
+impl<A, B> Bottom for Sum<A, B>
+	where
+		A: CompleteLattice,
+		B: CompleteLattice
+{
+	fn bottom() -> Self
+	{
+		Sum::Bottom
+	}
+}
+
+impl<A, B> Top for Sum<A, B>
+	where
+		A: CompleteLattice,
+		B: CompleteLattice
+{
+	fn top() -> Self
+	{
+		Sum::Top
+	}
+	
+	fn is_top(&self) -> bool
+	{
+		matches!(self, Sum::Top)
+	}
+}
+
+impl<A, B> Meet for Sum<A, B>
+	where
+		A: CompleteLattice + Meet,
+		B: CompleteLattice + Meet
+{
+	fn meet_assign(&mut self, other: &Self)
+	{
+		match (&mut *self, other) {
+			(_, Sum::Top) => (),
+			(Sum::Top, _) => *self = other.clone(),
+			(Sum::Left(a1), Sum::Left(a2)) => a1.meet_assign(a2),
+			(Sum::Right(b1), Sum::Right(b2)) => b1.meet_assign(b2),
+			_ => *self = Sum::Bottom,
+		}
+		self.normalize();
+	}
+}
+
+impl<A, B> PartialOrd for Sum<A, B>
+	where
+		A: CompleteLattice,
+		B: CompleteLattice
+{
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		match (self.is_bottom(), other.is_bottom()) {
+			(true, true) => return Some(Ordering::Equal),
+			(true, false) => return Some(Ordering::Less),
+			(false, true) => return Some(Ordering::Greater),
+			(false, false) => (),
+		}
+		match (self, other) {
+			(Sum::Top, Sum::Top) => Some(Ordering::Equal),
+			(Sum::Top, _) => Some(Ordering::Greater),
+			(_, Sum::Top) => Some(Ordering::Less),
+			(Sum::Left(a1), Sum::Left(a2)) => a1.partial_cmp(a2),
+			(Sum::Right(b1), Sum::Right(b2)) => b1.partial_cmp(b2),
+			_ => None,
+		}
+	}
+}
+
+impl<A, B> PartialEq for Sum<A, B>
+	where
+		A: CompleteLattice,
+		B: CompleteLattice
+{
+	fn eq(&self, other: &Self) -> bool
+	{
+		self.partial_cmp(other) == Some(Ordering::Equal)
+	}
+}
+
+impl<A, B> Add for Sum<A, B>
+	where
+		A: CompleteLattice,
+		B: CompleteLattice
+{
+	type Output = Self;
+	fn add(mut self, rhs: Self) -> Self::Output
+	{
+		self += rhs;
+		self
+	}
+}
+
+impl<'a, A, B> Add<&'a Self> for Sum<A, B>
+	where
+		A: CompleteLattice,
+		B: CompleteLattice
+{
+	type Output = Self;
+	fn add(mut self, rhs: &'a Self) -> Self::Output
+	{
+		self += rhs;
+		self
+	}
+}
+
+impl<A, B> AddAssign for Sum<A, B>
+	where
+		A: CompleteLattice,
+		B: CompleteLattice
+{
+	fn add_assign(&mut self, rhs: Self)
+	{
+		if self.is_bottom() {
+			*self = rhs;
+		} else if !rhs.is_bottom() {
+			match (&mut *self, rhs) {
+				(Sum::Left(a1), Sum::Left(a2)) => *a1 += a2,
+				(Sum::Right(b1), Sum::Right(b2)) => *b1 += b2,
+				_ => *self = Sum::Top,
+			}
+		}
+		self.normalize();
+	}
+}
+
+impl<'a, A, B> AddAssign<&'a Self> for Sum<A, B>
+	where
+		A: CompleteLattice,
+		B: CompleteLattice
+{
+	fn add_assign(&mut self, rhs: &'a Self)
+	{
+		if self.is_bottom() {
+			*self = rhs.clone();
+		} else if !rhs.is_bottom() {
+			match (&mut *self, rhs) {
+				(Sum::Left(a1), Sum::Left(a2)) => *a1 += a2,
+				(Sum::Right(b1), Sum::Right(b2)) => *b1 += b2,
+				_ => *self = Sum::Top,
+			}
+		}
+		self.normalize();
+	}
+}
@@ -1,7 +1,10 @@
 use super::*;
 
-use std::ops::{
-	Index, IndexMut
+use std::{
+	borrow::Cow,
+	ops::{
+		Index, IndexMut
+	}
 };
 
 trait_alias!(TFSpaceKey: Copy, Eq);
@@ -20,9 +23,11 @@ trait_alias!(TFSpaceElement: CompleteLattice);
 /// ### Trait requirements
 ///
 /// * CompleteLattice
-/// * Index
+/// * Index: May panic if the key isn't present. Use [`get`] to treat missing keys as bottom.
 /// * IndexMut: Must not fail. If an index is present in the object it should be added.
 ///
+/// [`get`]: #method.get
+///
 pub trait TFSpace<K,E>: CompleteLattice + Index<K, Output=E> + IndexMut<K>
 	where
 		K: TFSpaceKey,
@@ -40,4 +45,19 @@ pub trait TFSpace<K,E>: CompleteLattice + Index<K, Output=E> + IndexMut<K>
 	{
 		self.keys().any(|key| key == k)
 	}
+	
+	///
+	/// The element the given key maps to.
+	///
+	/// Unlike indexing, this doesn't panic if the key isn't present,
+	/// but returns bottom, which is what a missing key maps to.
+	///
+	fn get(&self, k: K) -> Cow<'_, E>
+	{
+		if self.has_key(k) {
+			Cow::Borrowed(&self[k])
+		} else {
+			Cow::Owned(E::bottom())
+		}
+	}
 }
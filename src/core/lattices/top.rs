@@ -0,0 +1,20 @@
+
+pub trait Top
+{
+	///
+	/// Returns the top (Least Upper Bound) element of the
+	/// [Complete Lattice](http://mathworld.wolfram.com/CompleteLattice.html).
+	///
+	fn top() -> Self;
+	
+	///
+	/// Whether this instance is the top element of the
+	/// [Complete Lattice](http://mathworld.wolfram.com/CompleteLattice.html).
+	///
+	fn is_top(&self) -> bool;
+}
+
+pub fn top<T: Top>() -> T
+{
+	T::top()
+}
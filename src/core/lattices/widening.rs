@@ -0,0 +1,32 @@
+
+use crate::core::CompleteLattice;
+
+///
+/// A widening operator for a [Complete Lattice].
+///
+/// Used to guarantee termination of analyses over lattices with infinite
+/// ascending chains. Given `w = e1.widen(&e2)` it must hold that `e1 <= w` and
+/// `e2 <= w`, and any sequence `w0, w1 = w0.widen(&e1), w2 = w1.widen(&e2), ...`
+/// must eventually stabilize.
+///
+/// [Complete Lattice]: http://mathworld.wolfram.com/CompleteLattice.html
+///
+pub trait Widen: CompleteLattice
+{
+	fn widen(&self, next: &Self) -> Self;
+}
+
+///
+/// A narrowing operator for a [Complete Lattice].
+///
+/// Used to regain precision lost by [`Widen`]. Given `next <= self` and
+/// `n = self.narrow(&next)` it must hold that `next <= n <= self`, and any sequence
+/// `n0, n1 = n0.narrow(&e1), n2 = n1.narrow(&e2), ...` must eventually stabilize.
+///
+/// [Complete Lattice]: http://mathworld.wolfram.com/CompleteLattice.html
+/// [`Widen`]: trait.Widen.html
+///
+pub trait Narrow: CompleteLattice
+{
+	fn narrow(&self, next: &Self) -> Self;
+}
@@ -11,3 +11,7 @@ pub use self::{
 	lattices::*,
 	analysis::*,
 };
+#[cfg(feature = "derive")]
+pub use progysis_derive::{
+	CompleteLattice, Bottom, SubLattice
+};
@@ -14,5 +14,6 @@
 mod trait_alias;
 pub mod core;
 pub mod common;
+pub mod testing;
 
 pub use self::trait_alias::*;
\ No newline at end of file
@@ -0,0 +1,249 @@
+
+use crate::core::CompleteLattice;
+use std::fmt::{
+	Debug, Display, Formatter, Result as FmtResult
+};
+
+///
+/// A lattice law that doesn't hold for the given elements.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Violation<L>
+{
+	///
+	/// The name of the law that was violated.
+	///
+	pub law: &'static str,
+	
+	///
+	/// The elements the law was checked on, in the order they appear in the law.
+	///
+	pub elements: Vec<L>,
+}
+
+impl<L> Violation<L>
+{
+	///
+	/// A violation of the named law by the given elements.
+	///
+	/// Useful for reporting violations found by custom checks in the same way.
+	///
+	pub fn new(law: &'static str, elements: Vec<L>) -> Self
+	{
+		Self{law, elements}
+	}
+}
+
+impl<L> Display for Violation<L>
+	where
+		L: Debug
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{} violated by {:?}", self.law, self.elements)
+	}
+}
+
+///
+/// Checks all the laws of a [Complete Lattice] on the given samples.
+///
+/// I.e. all of [`check_partial_order`], [`check_join`] and [`check_bottom`].
+///
+/// [Complete Lattice]: http://mathworld.wolfram.com/CompleteLattice.html
+/// [`check_partial_order`]: fn.check_partial_order.html
+/// [`check_join`]: fn.check_join.html
+/// [`check_bottom`]: fn.check_bottom.html
+///
+pub fn check_lattice_laws<L>(samples: &[L]) -> Vec<Violation<L>>
+	where
+		L: CompleteLattice
+{
+	let mut violations = check_partial_order(samples);
+	violations.extend(check_join(samples));
+	violations.extend(check_bottom(samples));
+	violations
+}
+
+///
+/// Panics, listing the violations, if any of the laws checked by [`check_lattice_laws`]
+/// don't hold for the given samples.
+///
+/// [`check_lattice_laws`]: fn.check_lattice_laws.html
+///
+pub fn assert_lattice_laws<L>(samples: &[L])
+	where
+		L: CompleteLattice + Debug
+{
+	assert_no_violations(check_lattice_laws(samples));
+}
+
+///
+/// Checks that `<=` is a partial order on the given samples, and that the other
+/// comparison operators agree with it:
+///
+/// * Reflexivity: `a <= a` and `a == a`.
+/// * Antisymmetry: `a <= b` and `b <= a` implies `a == b`.
+/// * Transitivity: `a <= b` and `b <= c` implies `a <= c`.
+/// * Consistency: `a < b` iff `a <= b` and `a != b`, `a >= b` iff `b <= a`,
+///   `a > b` iff `b < a`, and `partial_cmp` agrees with all of them.
+///
+#[allow(clippy::eq_op)]
+pub fn check_partial_order<L>(samples: &[L]) -> Vec<Violation<L>>
+	where
+		L: CompleteLattice
+{
+	let mut violations = Vec::new();
+	for a in samples {
+		if !(a <= a && a == a) {
+			violations.push(Violation::new("reflexivity", vec![a.clone()]));
+		}
+		for b in samples {
+			if a <= b && b <= a && a != b {
+				violations.push(Violation::new("antisymmetry", vec![a.clone(), b.clone()]));
+			}
+			if !consistent_comparisons(a, b) {
+				violations.push(Violation::new("comparison consistency",
+					vec![a.clone(), b.clone()]));
+			}
+			for c in samples {
+				if a <= b && b <= c && !a.le(c) {
+					violations.push(Violation::new("transitivity",
+						vec![a.clone(), b.clone(), c.clone()]));
+				}
+			}
+		}
+	}
+	violations
+}
+
+///
+/// Checks that `+` is the least upper bound on the given samples:
+///
+/// * Commutativity: `a + b == b + a`.
+/// * Associativity: `(a + b) + c == a + (b + c)`.
+/// * Idempotence: `a + a == a`.
+/// * Upper bound: `a <= a + b` and `b <= a + b`.
+/// * Least upper bound: `a <= c` and `b <= c` implies `a + b <= c`.
+/// * Consistency: `+` and `+=`, by value and by reference, and [`join_assign`]
+///   all give the same result, and [`join_assign`] reports a change iff there was one.
+///
+/// [`join_assign`]: ../core/trait.CompleteLattice.html#method.join_assign
+///
+pub fn check_join<L>(samples: &[L]) -> Vec<Violation<L>>
+	where
+		L: CompleteLattice
+{
+	let mut violations = Vec::new();
+	for a in samples {
+		if a.clone() + a != *a {
+			violations.push(Violation::new("join idempotence", vec![a.clone()]));
+		}
+		for b in samples {
+			let ab = a.clone() + b;
+			if ab != b.clone() + a {
+				violations.push(Violation::new("join commutativity", vec![a.clone(), b.clone()]));
+			}
+			if !(*a <= ab && *b <= ab) {
+				violations.push(Violation::new("join upper bound", vec![a.clone(), b.clone()]));
+			}
+			if !consistent_joins(a, b, &ab) {
+				violations.push(Violation::new("join consistency", vec![a.clone(), b.clone()]));
+			}
+			for c in samples {
+				if ab.clone() + c != a.clone() + &(b.clone() + c) {
+					violations.push(Violation::new("join associativity",
+						vec![a.clone(), b.clone(), c.clone()]));
+				}
+				if a <= c && b <= c && !ab.le(c) {
+					violations.push(Violation::new("join least upper bound",
+						vec![a.clone(), b.clone(), c.clone()]));
+				}
+			}
+		}
+	}
+	violations
+}
+
+///
+/// Checks that bottom is the least element and is identified by [`is_bottom`]:
+///
+/// * `bottom().is_bottom()`.
+/// * `bottom() <= a`.
+/// * `bottom() + a == a`.
+/// * `a.is_bottom()` iff `a == bottom()`.
+///
+/// [`is_bottom`]: ../core/trait.CompleteLattice.html#tymethod.is_bottom
+///
+pub fn check_bottom<L>(samples: &[L]) -> Vec<Violation<L>>
+	where
+		L: CompleteLattice
+{
+	let mut violations = Vec::new();
+	let bottom = L::bottom();
+	if !bottom.is_bottom() {
+		violations.push(Violation::new("bottom is_bottom", vec![bottom.clone()]));
+	}
+	for a in samples {
+		if !bottom.le(a) {
+			violations.push(Violation::new("bottom is least", vec![a.clone()]));
+		}
+		if L::bottom() + a != *a {
+			violations.push(Violation::new("bottom is join identity", vec![a.clone()]));
+		}
+		if a.is_bottom() != (*a == bottom) {
+			violations.push(Violation::new("is_bottom consistency", vec![a.clone()]));
+		}
+	}
+	violations
+}
+
+///
+/// Panics, listing the given violations, if there are any.
+///
+pub fn assert_no_violations<L>(violations: Vec<Violation<L>>)
+	where
+		L: Debug
+{
+	if !violations.is_empty() {
+		let list: Vec<_> = violations.iter().map(|v| v.to_string()).collect();
+		panic!("{} lattice law violation(s):\n{}", violations.len(), list.join("\n"));
+	}
+}
+
+// Helper functions
+
+fn consistent_comparisons<L>(a: &L, b: &L) -> bool
+	where
+		L: CompleteLattice
+{
+	use std::cmp::Ordering::*;
+	let le = a <= b;
+	let lt = a < b;
+	let expected = match (le, b <= a) {
+		(true, true) => Some(Equal),
+		(true, false) => Some(Less),
+		(false, true) => Some(Greater),
+		(false, false) => None,
+	};
+	lt == (le && a != b)
+		&& a.ge(b) == b.le(a)
+		&& a.gt(b) == b.lt(a)
+		&& a.partial_cmp(b) == expected
+}
+
+fn consistent_joins<L>(a: &L, b: &L, ab: &L) -> bool
+	where
+		L: CompleteLattice
+{
+	let mut by_value = a.clone();
+	by_value += b.clone();
+	let mut by_ref = a.clone();
+	by_ref += b;
+	let mut joined = a.clone();
+	let changed = joined.join_assign(b);
+	a.clone() + b.clone() == *ab
+		&& by_value == *ab
+		&& by_ref == *ab
+		&& joined == *ab
+		&& changed == (*ab != *a)
+}
@@ -0,0 +1,19 @@
+//!
+//! Reusable checks for testing user-defined lattices and analyses.
+//!
+//! Each check is given sample elements of a lattice and verifies that the lattice
+//! laws, or properties of transfer functions, hold for all combinations of them,
+//! returning the violations found.
+//! The samples should include bottom, top (if any) and elements that are
+//! incomparable to each other, as the checks can only find violations among them.
+//!
+
+mod lattice_laws;
+mod transfer;
+mod soundness;
+
+pub use self::{
+	lattice_laws::*,
+	transfer::*,
+	soundness::*,
+};
@@ -0,0 +1,171 @@
+use crate::core::Abstraction;
+use std::fmt::{
+	Debug, Display, Formatter, Result as FmtResult
+};
+
+///
+/// Concrete values that aren't described by the abstract element that should describe them.
+///
+/// `L` is the abstract lattice and `C` the type of the concrete values it abstracts.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Unsoundness<L, C>
+{
+	///
+	/// The abstract inputs followed by the abstract result.
+	///
+	pub abstract_values: Vec<L>,
+	
+	///
+	/// The concrete inputs, each described by the corresponding abstract input,
+	/// followed by the concrete result, which isn't described by the abstract result.
+	///
+	pub concrete_values: Vec<C>,
+}
+
+impl<L, C> Display for Unsoundness<L, C>
+	where
+		L: Debug,
+		C: Debug
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "soundness violated by concrete values {:?} not being described by abstract values {:?}",
+			self.concrete_values, self.abstract_values)
+	}
+}
+
+///
+/// Checks that the abstraction of each of the given concrete values describes it.
+///
+/// I.e. that `L::alpha(c).gamma_contains(c)` for all values `c`.
+///
+pub fn check_abstraction<L, C>(values: &[C]) -> Vec<Unsoundness<L, C>>
+	where
+		L: Abstraction<C>,
+		C: Clone
+{
+	values.iter()
+		.map(|c| (L::alpha(c), c))
+		.filter(|(a, c)| !a.gamma_contains(c))
+		.map(|(a, c)| Unsoundness{abstract_values: vec![a], concrete_values: vec![c.clone()]})
+		.collect()
+}
+
+///
+/// Checks that the given abstract function over-approximates the given concrete function.
+///
+/// I.e. that for all sample elements `a` and values `c` described by `a`,
+/// `abstract_fn(a)` describes `concrete_fn(c)`.
+/// The concrete function returns `None` for values it is undefined for.
+///
+pub fn check_sound<L, C, F, G>(samples: &[L], values: &[C], abstract_fn: F, concrete_fn: G)
+	-> Vec<Unsoundness<L, C>>
+	where
+		L: Abstraction<C>,
+		C: Clone,
+		F: Fn(&L) -> L,
+		G: Fn(&C) -> Option<C>
+{
+	let mut unsoundnesses = Vec::new();
+	for a in samples {
+		let result = abstract_fn(a);
+		for c in values.iter().filter(|c| a.gamma_contains(c)) {
+			if let Some(concrete_result) = concrete_fn(c) {
+				if !result.gamma_contains(&concrete_result) {
+					unsoundnesses.push(Unsoundness{
+						abstract_values: vec![a.clone(), result.clone()],
+						concrete_values: vec![c.clone(), concrete_result],
+					});
+				}
+			}
+		}
+	}
+	unsoundnesses
+}
+
+///
+/// Like [`check_sound`], but for binary functions.
+///
+/// I.e. checks that for all sample elements `a1`, `a2` and values `c1`, `c2` described by
+/// `a1` and `a2` respectively, `abstract_fn(a1, a2)` describes `concrete_fn(c1, c2)`.
+///
+/// [`check_sound`]: fn.check_sound.html
+///
+pub fn check_sound_binary<L, C, F, G>(samples: &[L], values: &[C], abstract_fn: F, concrete_fn: G)
+	-> Vec<Unsoundness<L, C>>
+	where
+		L: Abstraction<C>,
+		C: Clone,
+		F: Fn(&L, &L) -> L,
+		G: Fn(&C, &C) -> Option<C>
+{
+	let mut unsoundnesses = Vec::new();
+	for a1 in samples {
+		for a2 in samples {
+			let result = abstract_fn(a1, a2);
+			for c1 in values.iter().filter(|c| a1.gamma_contains(c)) {
+				for c2 in values.iter().filter(|c| a2.gamma_contains(c)) {
+					if let Some(concrete_result) = concrete_fn(c1, c2) {
+						if !result.gamma_contains(&concrete_result) {
+							unsoundnesses.push(Unsoundness{
+								abstract_values: vec![a1.clone(), a2.clone(), result.clone()],
+								concrete_values: vec![c1.clone(), c2.clone(), concrete_result],
+							});
+						}
+					}
+				}
+			}
+		}
+	}
+	unsoundnesses
+}
+
+///
+/// Panics with the first unsoundness found by [`check_abstraction`], if any.
+///
+/// [`check_abstraction`]: fn.check_abstraction.html
+///
+pub fn assert_abstraction<L, C>(values: &[C])
+	where
+		L: Abstraction<C> + Debug,
+		C: Clone + Debug
+{
+	if let Some(u) = check_abstraction::<L, C>(values).first() {
+		panic!("{}", u);
+	}
+}
+
+///
+/// Panics with the first unsoundness found by [`check_sound`], if any.
+///
+/// [`check_sound`]: fn.check_sound.html
+///
+pub fn assert_sound<L, C, F, G>(samples: &[L], values: &[C], abstract_fn: F, concrete_fn: G)
+	where
+		L: Abstraction<C> + Debug,
+		C: Clone + Debug,
+		F: Fn(&L) -> L,
+		G: Fn(&C) -> Option<C>
+{
+	if let Some(u) = check_sound(samples, values, abstract_fn, concrete_fn).first() {
+		panic!("{}", u);
+	}
+}
+
+///
+/// Panics with the first unsoundness found by [`check_sound_binary`], if any.
+///
+/// [`check_sound_binary`]: fn.check_sound_binary.html
+///
+pub fn assert_sound_binary<L, C, F, G>(samples: &[L], values: &[C], abstract_fn: F, concrete_fn: G)
+	where
+		L: Abstraction<C> + Debug,
+		C: Clone + Debug,
+		F: Fn(&L, &L) -> L,
+		G: Fn(&C, &C) -> Option<C>
+{
+	if let Some(u) = check_sound_binary(samples, values, abstract_fn, concrete_fn).first() {
+		panic!("{}", u);
+	}
+}
@@ -0,0 +1,175 @@
+use crate::core::{
+	CompleteLattice, Analysis, SubLattice
+};
+use graphene::core::{
+	Graph, Directed
+};
+use std::{
+	fmt::{
+		Debug, Display, Formatter, Result as FmtResult
+	},
+	hash::Hash,
+};
+
+///
+/// A pair of dependency values for which a transfer function doesn't have a property.
+///
+/// `R` is the lattice the transfer function returns, while `L` and `A` are the flow
+/// variable values and actions it was given.
+///
+#[derive(Debug)]
+pub struct Counterexample<'a, L, A, R>
+{
+	///
+	/// The name of the property that doesn't hold.
+	///
+	pub property: &'static str,
+	
+	///
+	/// The action, i.e. edge weight, the transfer function was given.
+	///
+	pub action: &'a A,
+	
+	///
+	/// The value of the flow variable being computed.
+	///
+	pub target: &'a L,
+	
+	///
+	/// The pair of dependency values.
+	///
+	pub dependencies: (&'a L, &'a L),
+	
+	///
+	/// The results that should have been related by the property.
+	///
+	/// For monotonicity, the results of the transfer function on each dependency,
+	/// where the first should be less than or equal to the second.
+	/// For distributivity, the result on the join of the dependencies and the join
+	/// of the results on each, which should be equal.
+	///
+	pub results: (R, R),
+}
+
+impl<'a, L, A, R> Display for Counterexample<'a, L, A, R>
+	where
+		L: Debug,
+		A: Debug,
+		R: Debug
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{} violated by action {:?} with target {:?} and dependencies {:?}, giving {:?}",
+			self.property, self.action, self.target, self.dependencies, self.results)
+	}
+}
+
+///
+/// Checks that the transfer function of the given analysis is monotone in its dependency
+/// for each of the given actions.
+///
+/// I.e. that for all sample values `a <= b` and targets `t`,
+/// `transfer(a, t, action) <= transfer(b, t, action)`.
+/// The analysis is only guaranteed to converge if this holds.
+///
+pub fn check_monotone<'a, N, G, L>(actions: &'a [G::EdgeWeight], samples: &'a [L])
+	-> Vec<Counterexample<'a, L, G::EdgeWeight, N::Lattice>>
+	where
+		N: Analysis<G, L>,
+		G: Graph<Directedness=Directed>,
+		G::Vertex: Hash,
+		L: CompleteLattice + SubLattice<N::Lattice>
+{
+	let mut counterexamples = Vec::new();
+	for action in actions {
+		for target in samples {
+			for a in samples {
+				for b in samples.iter().filter(|b| a <= *b) {
+					let a_result = N::transfer(a, target, action);
+					let b_result = N::transfer(b, target, action);
+					if !a_result.le(&b_result) {
+						counterexamples.push(Counterexample{
+							property: "monotonicity", action, target,
+							dependencies: (a, b), results: (a_result, b_result),
+						});
+					}
+				}
+			}
+		}
+	}
+	counterexamples
+}
+
+///
+/// Checks that the transfer function of the given analysis distributes over join
+/// in its dependency for each of the given actions.
+///
+/// I.e. that for all sample values `a`, `b` and targets `t`,
+/// `transfer(a + b, t, action) == transfer(a, t, action) + transfer(b, t, action)`.
+/// If this holds, the analysis computes the meet over all paths solution.
+///
+pub fn check_distributive<'a, N, G, L>(actions: &'a [G::EdgeWeight], samples: &'a [L])
+	-> Vec<Counterexample<'a, L, G::EdgeWeight, N::Lattice>>
+	where
+		N: Analysis<G, L>,
+		G: Graph<Directedness=Directed>,
+		G::Vertex: Hash,
+		L: CompleteLattice + SubLattice<N::Lattice>
+{
+	let mut counterexamples = Vec::new();
+	for action in actions {
+		for target in samples {
+			for a in samples {
+				for b in samples {
+					let joined = N::transfer(&(a.clone() + b), target, action);
+					let separate = N::transfer(a, target, action) + N::transfer(b, target, action);
+					if joined != separate {
+						counterexamples.push(Counterexample{
+							property: "distributivity", action, target,
+							dependencies: (a, b), results: (joined, separate),
+						});
+					}
+				}
+			}
+		}
+	}
+	counterexamples
+}
+
+///
+/// Panics with the first counterexample found by [`check_monotone`], if any.
+///
+/// [`check_monotone`]: fn.check_monotone.html
+///
+pub fn assert_monotone<N, G, L>(actions: &[G::EdgeWeight], samples: &[L])
+	where
+		N: Analysis<G, L>,
+		N::Lattice: Debug,
+		G: Graph<Directedness=Directed>,
+		G::Vertex: Hash,
+		G::EdgeWeight: Debug,
+		L: CompleteLattice + SubLattice<N::Lattice> + Debug
+{
+	if let Some(c) = check_monotone::<N, G, L>(actions, samples).first() {
+		panic!("{}", c);
+	}
+}
+
+///
+/// Panics with the first counterexample found by [`check_distributive`], if any.
+///
+/// [`check_distributive`]: fn.check_distributive.html
+///
+pub fn assert_distributive<N, G, L>(actions: &[G::EdgeWeight], samples: &[L])
+	where
+		N: Analysis<G, L>,
+		N::Lattice: Debug,
+		G: Graph<Directedness=Directed>,
+		G::Vertex: Hash,
+		G::EdgeWeight: Debug,
+		L: CompleteLattice + SubLattice<N::Lattice> + Debug
+{
+	if let Some(c) = check_distributive::<N, G, L>(actions, samples).first() {
+		panic!("{}", c);
+	}
+}
@@ -0,0 +1,99 @@
+use crate::common::lattices::{
+	Sign, SignPowerSet
+};
+use progysis::core::{
+	CompleteLattice, Bottom, SubLattice, PowerSet, U32, U64
+};
+
+#[derive(Clone, PartialEq, Debug, CompleteLattice, Bottom, SubLattice)]
+struct Named
+{
+	#[sub_lattice]
+	count: U32,
+	#[sub_lattice]
+	signs: SignPowerSet,
+	max: U64,
+}
+
+#[derive(Clone, PartialEq, Debug, CompleteLattice, Bottom, SubLattice)]
+struct Unnamed<L>(#[sub_lattice] L, U64)
+	where L: CompleteLattice;
+
+mod unscoped
+{
+	// The derived code must not rely on the lattice traits being in scope
+	#[derive(Clone, PartialEq, Debug, progysis::core::CompleteLattice, progysis::core::Bottom)]
+	pub struct Unscoped(pub progysis::core::U32);
+}
+
+#[test]
+fn bottom_test()
+{
+	let named = Named::bottom();
+	assert!(named.is_bottom());
+	assert_eq!(named, Named{count: U32(0), signs: SignPowerSet::bottom(), max: U64(0)});
+	
+	let unnamed = Unnamed::<U32>::bottom();
+	assert!(unnamed.is_bottom());
+	assert_eq!(unnamed, Unnamed(U32(0), U64(0)));
+}
+
+#[test]
+fn comparison_test()
+{
+	let n1 = Named{count: U32(1), signs: SignPowerSet::singleton(Sign::Plus), max: U64(0)};
+	let n2 = Named{count: U32(2), signs: SignPowerSet::singleton(Sign::Plus), max: U64(1)};
+	let n3 = Named{count: U32(3), signs: SignPowerSet::singleton(Sign::Minus), max: U64(1)};
+	
+	assert!(n1 < n2, "{:?} >= {:?}", n1, n2);
+	assert!(n2 > n1, "{:?} <= {:?}", n2, n1);
+	assert!(!n1.comparable_to(&n3));
+	assert!(!n2.comparable_to(&n3));
+	assert!(Named::bottom() < n1);
+}
+
+#[test]
+fn addition_test()
+{
+	let n1 = Named{count: U32(1), signs: SignPowerSet::singleton(Sign::Plus), max: U64(4)};
+	let n2 = Named{count: U32(3), signs: SignPowerSet::singleton(Sign::Minus), max: U64(1)};
+	let expected = Named{
+		count: U32(3),
+		signs: SignPowerSet::from_iter(vec![Sign::Plus, Sign::Minus]),
+		max: U64(4)
+	};
+	
+	assert_eq!(n1.clone() + n2.clone(), expected);
+	assert_eq!(n1.clone() + &n2, expected);
+	let mut n = n1.clone();
+	n += &n2;
+	assert_eq!(n, expected);
+	
+	let mut u = Unnamed(U32(1), U64(5));
+	u += Unnamed(U32(2), U64(0));
+	assert_eq!(u, Unnamed(U32(2), U64(5)));
+}
+
+#[test]
+fn sub_lattice_test()
+{
+	let mut n = Named{count: U32(1), signs: SignPowerSet::singleton(Sign::Plus), max: U64(4)};
+	let count: &U32 = n.sub_lattice_ref();
+	assert_eq!(*count, U32(1));
+	*n.sub_lattice_ref_mut() = SignPowerSet::singleton(Sign::Zero);
+	assert_eq!(n.signs, SignPowerSet::singleton(Sign::Zero));
+	
+	let u = Unnamed(U32(7), U64(5));
+	let inner: U32 = u.sub_lattice();
+	assert_eq!(inner, U32(7));
+}
+
+#[test]
+fn unscoped_test()
+{
+	let mut u = unscoped::Unscoped(U32(2));
+	assert!(!u.is_bottom());
+	assert!(unscoped::Unscoped::bottom().is_bottom());
+	assert!(u.join_assign(&unscoped::Unscoped(U32(5))));
+	assert_eq!(u, unscoped::Unscoped(U32(5)));
+}
@@ -0,0 +1,119 @@
+use super::*;
+
+use progysis::{
+	common::lattices::{BitSetPowerSet, BitSetPowerSetItem},
+	core::{CompleteLattice, PowerSet, Bottom, Top, Finite, Meet},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Def(usize);
+
+impl BitSetPowerSetItem for Def
+{
+	fn to_index(&self) -> usize
+	{
+		self.0
+	}
+	
+	fn from_index(index: usize) -> Self
+	{
+		Def(index)
+	}
+}
+
+impl Finite for Def
+{
+	fn universe() -> Vec<Self>
+	{
+		(0..100).map(Def).collect()
+	}
+}
+
+pub type DefPowerSet = BitSetPowerSet<Def>;
+
+#[test]
+fn comparison_test(){
+	let empty = DefPowerSet::bottom();
+	let d1 = DefPowerSet::singleton(Def(1));
+	let d70 = DefPowerSet::singleton(Def(70));
+	let d1_70 = DefPowerSet::from_iter(vec![Def(1), Def(70)]);
+	
+	assert!(empty.is_bottom());
+	assert!(empty < d1);
+	assert!(d1 < d1_70);
+	assert!(d70 < d1_70);
+	assert!(!d1.comparable_to(&d70));
+	assert_eq!(d1_70, DefPowerSet::from_iter(vec![Def(70), Def(1), Def(70)]));
+	assert_ne!(d1, d70);
+}
+
+#[test]
+fn addition_test(){
+	let d1 = DefPowerSet::singleton(Def(1));
+	let d70 = DefPowerSet::singleton(Def(70));
+	let d1_70 = DefPowerSet::from_iter(vec![Def(1), Def(70)]);
+	
+	assert_eq!(d1.clone() + d70.clone(), d1_70);
+	assert_eq!(d70.clone() + &d1, d1_70);
+	assert_eq!(DefPowerSet::bottom() + d70.clone(), d70);
+	
+	let mut v = d1.clone();
+	v += &d1_70;
+	assert_eq!(v, d1_70);
+	assert_eq!(v.all(), vec![Def(1), Def(70)]);
+}
+
+#[test]
+fn meet_test(){
+	let d1 = DefPowerSet::singleton(Def(1));
+	let d70 = DefPowerSet::singleton(Def(70));
+	let d1_70 = DefPowerSet::from_iter(vec![Def(1), Def(70)]);
+	
+	assert_eq!(d1_70.clone().meet(&d70), d70);
+	assert_eq!(d1_70.clone().meet(&d1), d1);
+	
+	// Meeting must not leave behind empty words
+	let empty = d70.clone().meet(&d1);
+	assert!(empty.is_bottom());
+	assert_eq!(empty, DefPowerSet::bottom());
+}
+
+#[test]
+fn top_test(){
+	let top = DefPowerSet::top();
+	assert!(top.is_top());
+	assert_eq!(top.all().len(), 100);
+	assert!(!DefPowerSet::singleton(Def(3)).is_top());
+	assert!(DefPowerSet::singleton(Def(99)) < top);
+}
+
+#[test]
+fn set_operations_test(){
+	let mut v = DefPowerSet::from_iter(vec![Def(130), Def(1), Def(64)]);
+	assert_eq!(v.len(), 3);
+	assert!(v.contains(&Def(64)));
+	assert!(!v.contains(&Def(63)));
+	assert_eq!(v.iter().map(|d| d.0).collect::<Vec<_>>(), vec![1, 64, 130]);
+	
+	assert!(v.insert(Def(5)));
+	assert!(!v.insert(Def(5)));
+	assert!(v.remove(&Def(130)));
+	assert!(!v.remove(&Def(130)));
+	assert_eq!(v, DefPowerSet::from_iter(vec![Def(1), Def(5), Def(64)]));
+	
+	// Removing the last items must not leave behind empty words
+	v.remove(&Def(1));
+	v.remove(&Def(5));
+	v.remove(&Def(64));
+	assert!(v.is_empty());
+	assert_eq!(v, DefPowerSet::bottom());
+}
+
+#[test]
+fn join_assign_test(){
+	let mut v = DefPowerSet::singleton(Def(1));
+	assert!(!v.join_assign(&DefPowerSet::bottom()));
+	assert!(!v.join_assign(&DefPowerSet::singleton(Def(1))));
+	assert!(v.join_assign(&DefPowerSet::singleton(Def(70))));
+	assert_eq!(v, DefPowerSet::from_iter(vec![Def(1), Def(70)]));
+}
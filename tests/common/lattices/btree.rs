@@ -0,0 +1,69 @@
+use super::*;
+
+use progysis::{
+	common::lattices::{BTreePowerSet, BTreeTFSpace},
+	core::{CompleteLattice, PowerSet, TFSpace, Bottom, Meet},
+};
+
+type U32PowerSet = BTreePowerSet<u32>;
+type StringU32TFSpace = BTreeTFSpace<&'static str, U32PowerSet>;
+
+#[test]
+fn power_set_order_test(){
+	let set = U32PowerSet::from_iter(vec![7, 3, 11, 1]);
+	
+	assert_eq!(set.all().into_iter().collect::<Vec<_>>(), vec![1, 3, 7, 11]);
+	assert_eq!(format!("{:?}", set), "BTreePowerSet { set: {1, 3, 7, 11} }");
+}
+
+#[test]
+fn power_set_lattice_test(){
+	let s1 = U32PowerSet::singleton(1);
+	let s2 = U32PowerSet::singleton(2);
+	let s12 = U32PowerSet::from_iter(vec![2, 1]);
+	
+	assert!(U32PowerSet::bottom().is_bottom());
+	assert!(s1 < s12);
+	assert!(!s1.comparable_to(&s2));
+	assert_eq!(s1.clone() + &s2, s12);
+	assert_eq!(s12.clone().meet(&s2), s2);
+	
+	let mut v = s2.clone();
+	v += s1;
+	assert_eq!(v, s12);
+}
+
+#[test]
+fn tf_space_order_test(){
+	let mut space = StringU32TFSpace::bottom();
+	space["z"] = U32PowerSet::singleton(1);
+	space["a"] = U32PowerSet::singleton(2);
+	space["m"] = U32PowerSet::bottom();
+	
+	assert_eq!(space.keys().collect::<Vec<_>>(), vec!["a", "m", "z"]);
+	assert!(space.has_key("m"));
+	assert!(!space.has_key("b"));
+}
+
+#[test]
+fn tf_space_lattice_test(){
+	let mut t1 = StringU32TFSpace::bottom();
+	t1["x"] = U32PowerSet::singleton(1);
+	let mut t2 = StringU32TFSpace::bottom();
+	t2["x"] = U32PowerSet::singleton(2);
+	t2["y"] = U32PowerSet::singleton(3);
+	
+	let joined = t1.clone() + &t2;
+	assert_eq!(joined["x"], U32PowerSet::from_iter(vec![1, 2]));
+	assert_eq!(joined["y"], U32PowerSet::singleton(3));
+	assert!(t1 < joined);
+	assert!(t2 < joined);
+	assert!(!t1.comparable_to(&t2));
+	
+	let mut with_bottom = t1.clone();
+	with_bottom["z"] = U32PowerSet::bottom();
+	assert_eq!(with_bottom, t1);
+	
+	let met = joined.meet(&t1);
+	assert_eq!(met, t1);
+}
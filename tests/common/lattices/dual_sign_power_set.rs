@@ -0,0 +1,48 @@
+use super::*;
+
+use progysis::core::{
+	CompleteLattice, PowerSet, Bottom, Top, Meet, Dual
+};
+use crate::common::lattices::sign_power_set::Sign::*;
+
+pub type DualSignPowerSet = Dual<SignPowerSet>;
+
+#[test]
+fn bottom_top_test(){
+	let bottom = DualSignPowerSet::bottom();
+	let top = DualSignPowerSet::top();
+	assert!(bottom.is_bottom());
+	assert!(top.is_top());
+	assert_eq!(bottom, Dual(SignPowerSet::top()));
+	assert_eq!(top, Dual(SignPowerSet::bottom()));
+	assert!(bottom < top, "{:?} >= {:?}", bottom, top);
+}
+
+#[test]
+fn comparison_test(){
+	let plus = Dual(SignPowerSet::singleton(Plus));
+	let minus = Dual(SignPowerSet::singleton(Minus));
+	let plus_minus = Dual(SignPowerSet::from_iter(vec![Plus, Minus]));
+	
+	assert!(plus_minus < plus, "{:?} >= {:?}", plus_minus, plus);
+	assert!(plus > plus_minus, "{:?} <= {:?}", plus, plus_minus);
+	assert!(!plus.comparable_to(&minus));
+}
+
+#[test]
+fn addition_test(){
+	let plus = Dual(SignPowerSet::singleton(Plus));
+	let plus_minus = Dual(SignPowerSet::from_iter(vec![Plus, Minus]));
+	let plus_zero = Dual(SignPowerSet::from_iter(vec![Plus, Zero]));
+	
+	assert_eq!(plus_minus.clone() + plus_zero.clone(), plus);
+	assert_eq!(plus_minus.clone() + DualSignPowerSet::bottom(), plus_minus);
+	assert_eq!(plus.clone().meet(&plus_zero), plus_zero);
+	
+	let mut v = plus_zero.clone();
+	v += &plus_minus;
+	assert_eq!(v, plus);
+	assert_eq!(*v.inner(), SignPowerSet::singleton(Plus));
+	v.inner_mut().insert(Zero);
+	assert_eq!(v.into_inner(), SignPowerSet::from_iter(vec![Plus, Zero]));
+}
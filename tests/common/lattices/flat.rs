@@ -0,0 +1,69 @@
+use super::*;
+
+use progysis::{
+	common::lattices::{Flat, HashTFSpace},
+	core::{CompleteLattice, Bottom, Top, Meet, TFSpace},
+};
+
+#[test]
+fn comparison_test(){
+	let bottom = Flat::<i32>::bottom();
+	let one = Flat::Value(1);
+	let two = Flat::Value(2);
+	let top = Flat::<i32>::top();
+	
+	assert!(bottom < one);
+	assert!(one < top);
+	assert!(bottom < top);
+	assert!(one <= one.clone());
+	assert!(!one.comparable_to(&two));
+	assert_ne!(one, two);
+}
+
+#[test]
+fn addition_test(){
+	let bottom = Flat::<i32>::bottom();
+	let one = Flat::Value(1);
+	let two = Flat::Value(2);
+	let top = Flat::<i32>::top();
+	
+	assert_eq!(bottom.clone() + one.clone(), one);
+	assert_eq!(one.clone() + one.clone(), one);
+	assert_eq!(one.clone() + two.clone(), top);
+	assert_eq!(top.clone() + &one, top);
+	
+	let mut v = bottom.clone();
+	v += &two;
+	assert_eq!(v, two);
+	assert_eq!(v.value(), Some(&2));
+}
+
+#[test]
+fn meet_test(){
+	let bottom = Flat::<i32>::bottom();
+	let one = Flat::Value(1);
+	let two = Flat::Value(2);
+	let top = Flat::<i32>::top();
+	
+	assert_eq!(top.clone().meet(&one), one);
+	assert_eq!(one.clone().meet(&one), one);
+	assert_eq!(one.clone().meet(&two), bottom);
+	assert_eq!(bottom.clone().meet(&top), bottom);
+}
+
+#[test]
+fn tf_space_test(){
+	let mut f1: HashTFSpace<&str, Flat<i32>> = HashTFSpace::bottom();
+	let mut f2 = HashTFSpace::bottom();
+	f1["x"] = Flat::Value(1);
+	f1["y"] = Flat::Value(2);
+	f2["x"] = Flat::Value(1);
+	f2["y"] = Flat::Value(3);
+	
+	let f3 = f1.clone() + &f2;
+	assert_eq!(f3["x"], Flat::Value(1));
+	assert_eq!(f3["y"], Flat::Top);
+	assert!(f1 < f3 && f2 < f3);
+	assert!(!f1.comparable_to(&f2));
+	assert!(f3.has_key("x"));
+}
@@ -0,0 +1,83 @@
+use super::*;
+
+use progysis::{
+	common::lattices::{Flat, Interval, IntervalBound},
+	core::{CompleteLattice, Bottom, Top, Meet, Widen, Narrow},
+};
+
+#[test]
+fn comparison_test(){
+	let empty = Interval::bottom();
+	let zero_ten = Interval::new(0, 10);
+	let five_twenty = Interval::new(5, 20);
+	let two_three = Interval::new(2, 3);
+	let top = Interval::top();
+	
+	assert!(empty.is_bottom());
+	assert!(top.is_top());
+	assert!(Interval::new(3, 2).is_bottom());
+	assert!(empty < two_three);
+	assert!(two_three < zero_ten);
+	assert!(zero_ten < top);
+	assert!(Interval::at_least(3) < top);
+	assert!(!zero_ten.comparable_to(&five_twenty));
+	assert_eq!(zero_ten, Interval::new(0, 10));
+}
+
+#[test]
+fn addition_test(){
+	let zero_ten = Interval::new(0, 10);
+	let five_twenty = Interval::new(5, 20);
+	
+	assert_eq!(zero_ten + five_twenty, Interval::new(0, 20));
+	assert_eq!(Interval::bottom() + zero_ten, zero_ten);
+	assert_eq!(Interval::at_most(2) + zero_ten, Interval::at_most(10));
+	assert_eq!(zero_ten.meet(&five_twenty), Interval::new(5, 10));
+	assert_eq!(zero_ten.meet(&Interval::new(11, 12)), Interval::bottom());
+}
+
+#[test]
+fn arithmetic_test(){
+	let a = Interval::new(-2, 3);
+	let b = Interval::new(4, 5);
+	
+	assert_eq!(a.plus(&b), Interval::new(2, 8));
+	assert_eq!(a.minus(&b), Interval::new(-7, -1));
+	assert_eq!(a.negate(), Interval::new(-3, 2));
+	assert_eq!(a.times(&b), Interval::new(-10, 15));
+	assert_eq!(b.divide(&a), Interval::new(-5, 5));
+	assert_eq!(Interval::new(10, 20).divide(&Interval::new(2, 5)), Interval::new(2, 10));
+	assert_eq!(b.divide(&Interval::constant(0)), Interval::bottom());
+	assert_eq!(Interval::at_least(1).times(&Interval::constant(-2)), Interval::at_most(-2));
+	assert_eq!(Interval::top().times(&Interval::constant(0)), Interval::constant(0));
+	assert_eq!(Interval::at_least(1).divide(&Interval::at_least(1)), Interval::at_least(0));
+	assert_eq!(Interval::constant(i64::MAX).plus(&Interval::constant(1)),
+			   Interval::from_bounds(IntervalBound::Int(i64::MAX), IntervalBound::PosInf));
+	assert_eq!(Interval::bottom().plus(&b), Interval::bottom());
+}
+
+#[test]
+fn comparison_operations_test(){
+	let zero_ten = Interval::new(0, 10);
+	
+	assert_eq!(zero_ten.less_than(&Interval::constant(11)), Flat::Value(true));
+	assert_eq!(zero_ten.less_than(&Interval::constant(10)), Flat::Top);
+	assert_eq!(zero_ten.less_equal(&Interval::constant(10)), Flat::Value(true));
+	assert_eq!(zero_ten.greater_than(&Interval::constant(-1)), Flat::Value(true));
+	assert_eq!(zero_ten.greater_equal(&Interval::constant(11)), Flat::Value(false));
+	assert_eq!(Interval::constant(3).equal_to(&Interval::constant(3)), Flat::Value(true));
+	assert_eq!(zero_ten.equal_to(&Interval::new(11, 12)), Flat::Value(false));
+	assert_eq!(zero_ten.not_equal(&Interval::constant(3)), Flat::Top);
+	assert_eq!(zero_ten.less_than(&Interval::bottom()), Flat::Bottom);
+}
+
+#[test]
+fn widening_test(){
+	let zero_one = Interval::new(0, 1);
+	let widened = zero_one.widen(&Interval::new(0, 2));
+	assert_eq!(widened, Interval::at_least(0));
+	assert_eq!(widened.widen(&Interval::new(-1, 3)), Interval::top());
+	assert_eq!(zero_one.widen(&Interval::constant(1)), zero_one);
+	assert_eq!(widened.narrow(&Interval::new(0, 10)), Interval::new(0, 10));
+	assert_eq!(zero_one.narrow(&Interval::constant(1)), zero_one);
+}
@@ -0,0 +1,130 @@
+use super::*;
+
+use progysis::{
+	common::lattices::{Flat, KnownBits, HashTFSpace},
+	core::{CompleteLattice, Bottom, Top, Meet, TFSpace},
+	testing::{assert_lattice_laws, assert_abstraction, assert_sound, assert_sound_binary},
+};
+
+type Byte = KnownBits<u8>;
+
+/// Elements knowing various combinations of bits.
+fn byte_samples() -> Vec<Byte>
+{
+	let mut samples = vec![Byte::bottom(), Byte::top()];
+	for (zeros, ones) in [(0x0f, 0x00), (0x00, 0x81), (0xf0, 0x05), (0x3c, 0xc3), (0x80, 0x01), (0x01, 0x02)] {
+		samples.push(Byte::new(zeros, ones));
+	}
+	samples.extend([0u8, 1, 2, 3, 7, 8, 0x80, 0xff].iter().map(|v| Byte::constant(*v)));
+	samples
+}
+
+#[test]
+fn bottom_top_test(){
+	let bottom = Byte::bottom();
+	assert!(bottom.is_bottom());
+	assert!(Byte::new(0x01, 0x01).is_bottom());
+	assert_eq!(Byte::new(0x01, 0x01), bottom);
+	assert_eq!(bottom.known_ones(), None);
+	assert!(!bottom.contains(0));
+	
+	let top = Byte::top();
+	assert!(top.is_top());
+	assert!((0..=255).all(|v| top.contains(v)));
+	assert!(bottom < Byte::constant(3) && Byte::constant(3) < top);
+	assert_eq!(format!("{:?}", Byte::new(0x0a, 0x05)), "0b????0101");
+	assert_eq!(format!("{:?}", bottom), "0b_");
+}
+
+#[test]
+fn join_meet_test(){
+	let three = Byte::constant(3);
+	let five = Byte::constant(5);
+	let joined = three + five;
+	assert_eq!(joined, Byte::new(0xf8, 0x01));
+	assert_eq!(joined.known_zeros(), Some(0xf8));
+	assert_eq!(joined.value(), None);
+	assert_eq!(three.value(), Some(3));
+	assert!(!three.comparable_to(&five));
+	assert!(three < joined);
+	
+	assert_eq!(joined.meet(&Byte::new(0x02, 0x04)), five);
+	assert!(three.meet(&five).is_bottom());
+	
+	let mut j = three;
+	assert!(j.join_assign(&five));
+	assert!(!j.join_assign(&three));
+}
+
+#[test]
+fn operations_test(){
+	let low = Byte::new(0xf0, 0x00);
+	let odd = Byte::new(0x00, 0x01);
+	
+	assert_eq!(low.and(&Byte::constant(0x3c)), Byte::new(0xf3, 0x00));
+	assert_eq!(low.or(&Byte::constant(0x80)), Byte::new(0x70, 0x80));
+	assert_eq!(odd.xor(&Byte::constant(1)), Byte::new(0x01, 0x00));
+	assert_eq!(odd.not(), Byte::new(0x01, 0x00));
+	assert_eq!(odd.shift_left(&Byte::constant(4)), Byte::new(0x0f, 0x10));
+	assert_eq!(low.shift_right(&Byte::constant(2)), Byte::new(0xfc, 0x00));
+	assert_eq!(odd.shift_left(&Byte::constant(8)), Byte::constant(0));
+	
+	// Even plus odd is odd, odd plus odd is even
+	assert_eq!(odd.plus(&Byte::new(0x01, 0x00)), odd);
+	assert_eq!(odd.plus(&odd), Byte::new(0x01, 0x00));
+	assert_eq!(Byte::constant(200).plus(&Byte::constant(100)), Byte::constant(44));
+	assert_eq!(Byte::constant(1).minus(&Byte::constant(2)), Byte::constant(0xff));
+	assert!(low.plus(&Byte::bottom()).is_bottom());
+	
+	assert_eq!(low.less_than(&Byte::constant(0x10)), Flat::Value(true));
+	assert_eq!(low.less_equal(&Byte::constant(0x0e)), Flat::Top);
+	assert_eq!(Byte::constant(0x10).greater_equal(&low), Flat::Value(true));
+	assert_eq!(odd.equal_to(&Byte::constant(2)), Flat::Value(false));
+	assert_eq!(odd.not_equal(&Byte::constant(2)), Flat::Value(true));
+	assert_eq!(Byte::constant(2).equal_to(&Byte::constant(2)), Flat::Value(true));
+	assert_eq!(odd.equal_to(&Byte::constant(3)), Flat::Top);
+	assert_eq!(odd.greater_than(&Byte::bottom()), Flat::Bottom);
+}
+
+#[test]
+fn laws_test(){
+	assert_lattice_laws(&byte_samples());
+	assert_lattice_laws(&[KnownBits::bottom(), KnownBits::top(), KnownBits::constant(u64::MAX),
+		KnownBits::new(1u64 << 63, 1)]);
+}
+
+#[test]
+fn sound_test(){
+	let samples = byte_samples();
+	let values: Vec<u8> = (0..=255).collect();
+	
+	assert_abstraction::<Byte, _>(&values);
+	assert_sound(&samples, &values, |a| a.not(), |c| Some(!c));
+	assert_sound_binary(&samples, &values, |a, b| a.and(b), |c, d| Some(c & d));
+	assert_sound_binary(&samples, &values, |a, b| a.or(b), |c, d| Some(c | d));
+	assert_sound_binary(&samples, &values, |a, b| a.xor(b), |c, d| Some(c ^ d));
+	assert_sound_binary(&samples, &values, |a, b| a.plus(b), |c, d| Some(c.wrapping_add(*d)));
+	assert_sound_binary(&samples, &values, |a, b| a.minus(b), |c, d| Some(c.wrapping_sub(*d)));
+	assert_sound_binary(&samples, &values, |a, b| a.shift_left(b),
+		|c, d| Some(c.checked_shl(*d as u32).unwrap_or(0)));
+	assert_sound_binary(&samples, &values, |a, b| a.shift_right(b),
+		|c, d| Some(c.checked_shr(*d as u32).unwrap_or(0)));
+}
+
+#[test]
+fn register_tf_space_test(){
+	let mut registers = HashTFSpace::<&str, KnownBits<u32>>::bottom();
+	registers["r0"] = KnownBits::constant(0x10);
+	registers["r1"] = KnownBits::new(0x0f, 0);
+	let sum = registers.get("r0").plus(&registers.get("r1"));
+	registers["r2"] = sum;
+	
+	assert_eq!(registers["r2"].known_zeros(), Some(0x0f));
+	assert!(registers.get("r3").is_bottom());
+	
+	let mut other = HashTFSpace::bottom();
+	other["r2"] = KnownBits::constant(0);
+	registers += other;
+	assert_eq!(registers["r2"].known_zeros(), Some(0x0f));
+	assert_eq!(registers["r0"], KnownBits::constant(0x10));
+}
@@ -0,0 +1,72 @@
+use super::*;
+
+use progysis::core::{
+	CompleteLattice, PowerSet, Bottom, Top, Meet, Lift, SubLattice
+};
+use crate::common::lattices::sign_power_set::Sign::*;
+
+pub type LiftSignPowerSet = Lift<SignPowerSet>;
+
+#[test]
+fn bottom_test(){
+	let unreachable = LiftSignPowerSet::bottom();
+	let empty = LiftSignPowerSet::reachable(SignPowerSet::bottom());
+	assert!(unreachable.is_bottom());
+	assert!(!unreachable.is_reachable());
+	assert!(!empty.is_bottom());
+	assert!(empty.is_reachable());
+	assert!(unreachable < empty);
+	assert_ne!(unreachable, empty);
+	assert!(LiftSignPowerSet::top().is_top());
+}
+
+#[test]
+fn join_test(){
+	let unreachable = LiftSignPowerSet::unreachable();
+	let empty = LiftSignPowerSet::reachable(SignPowerSet::bottom());
+	let plus = LiftSignPowerSet::singleton(Plus);
+	let minus = LiftSignPowerSet::singleton(Minus);
+	
+	assert_eq!(unreachable.clone() + &empty, empty);
+	assert_eq!(plus.clone() + &unreachable, plus);
+	assert_eq!(plus.clone() + minus.clone(), LiftSignPowerSet::from_iter(vec![Plus, Minus]));
+	assert!(!plus.comparable_to(&minus));
+	
+	let mut v = unreachable.clone();
+	assert!(!v.join_assign(&unreachable));
+	assert!(v.join_assign(&empty));
+	assert!(v.join_assign(&plus));
+	assert!(!v.join_assign(&plus));
+	assert_eq!(v, plus);
+	
+	assert_eq!(plus.clone().meet(&minus), empty);
+	assert_eq!(plus.meet(&unreachable), unreachable);
+}
+
+#[test]
+fn map_test(){
+	let unreachable = LiftSignPowerSet::unreachable();
+	let plus = LiftSignPowerSet::singleton(Plus);
+	
+	assert_eq!(plus.map(|v| v.negate()), LiftSignPowerSet::singleton(Minus));
+	assert_eq!(unreachable.map(|v| v.negate()), unreachable);
+	assert_eq!(plus.value(), Some(&SignPowerSet::singleton(Plus)));
+	assert_eq!(unreachable.value(), None);
+}
+
+#[test]
+fn forwarding_test(){
+	let mut v = LiftSignPowerSet::unreachable();
+	assert!(!v.contains(&Plus));
+	assert!(v.insert(Plus));
+	assert!(v.is_reachable());
+	assert_eq!(v.len(), 1);
+	
+	// An empty set is still reachable
+	assert!(LiftSignPowerSet::from_iter(vec![]).is_reachable());
+	
+	let mut v = LiftSignPowerSet::unreachable();
+	let inner: &mut SignPowerSet = v.sub_lattice_ref_mut();
+	*inner += SignPowerSet::singleton(Zero);
+	assert_eq!(v, LiftSignPowerSet::singleton(Zero));
+}
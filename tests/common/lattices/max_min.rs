@@ -0,0 +1,61 @@
+use super::*;
+
+use progysis::{
+	core::{CompleteLattice, Bottom, Top, Meet, Widen, Max, Min, Or, And, U32},
+	testing::assert_lattice_laws,
+};
+
+#[test]
+fn max_test(){
+	assert_eq!(Max::<i8>::bottom(), Max(i8::MIN));
+	assert_eq!(Max::<i8>::top(), Max(i8::MAX));
+	assert_eq!(Max(-3) + Max(2), Max(2));
+	assert_eq!(Max(-3).meet(&Max(2)), Max(-3));
+	assert!(Max(-3) < Max(2));
+	let c = Max('c');
+	let mut a = Max('a');
+	a += &c;
+	assert_eq!(a, c);
+	
+	assert_lattice_laws(&[Max(i8::MIN), Max(-1), Max(0), Max(5), Max(i8::MAX)]);
+	assert_lattice_laws(&[Max('\0'), Max('a'), Max('z'), Max(char::MAX)]);
+}
+
+#[test]
+fn min_test(){
+	assert_eq!(Min::<u8>::bottom(), Min(u8::MAX));
+	assert_eq!(Min::<u8>::top(), Min(0));
+	assert_eq!(Min(3u8) + Min(2), Min(2));
+	assert_eq!(Min(3u8).meet(&Min(2)), Min(3));
+	assert!(Min(3u8) < Min(2));
+	assert!(Min(3u8).widen(&Min(2)).is_top());
+	assert_eq!(Min(3u8).widen(&Min(7)), Min(3));
+	
+	assert_lattice_laws(&[Min(0u8), Min(1), Min(100), Min(u8::MAX)]);
+}
+
+#[test]
+fn bool_test(){
+	assert!(Or::bottom() == Max(false));
+	assert_eq!(Max(false) + Max(true), Max(true));
+	assert!(And::bottom() == Min(true));
+	assert_eq!(Min(false) + Min(true), Min(false));
+	
+	assert_lattice_laws(&[Max(false), Max(true)]);
+	assert_lattice_laws(&[Min(false), Min(true)]);
+}
+
+#[test]
+fn u32_compatibility_test(){
+	let v: U32 = U32(4);
+	assert_eq!(v, Max(4u32));
+	assert_eq!(v.0, 4);
+	assert!(U32::top().is_top());
+	
+	let Max(x) = U32(5) + U32(2);
+	assert_eq!(x, 5);
+	match v {
+		Max(4) => (),
+		_ => panic!("Expected Max(4)"),
+	}
+}
@@ -1,7 +1,21 @@
 use super::*;
 
 mod sign_power_set;
+mod sign_intersection_power_set;
+mod dual_sign_power_set;
+mod lift_sign_power_set;
+mod flat;
+mod interval;
+mod known_bits;
+mod max_min;
+mod bit_set_power_set;
+mod btree;
 mod string_sign_tf_space;
+mod sum;
+mod reduced_product;
+mod vec_tf_space;
+mod persistent_tf_space;
+mod power_set_defaults;
 
 pub use self::{
 	sign_power_set::*,
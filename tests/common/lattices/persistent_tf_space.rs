@@ -0,0 +1,89 @@
+use super::*;
+
+use progysis::{
+	common::lattices::{PersistentTFSpace, HashTFSpace},
+	core::{CompleteLattice, TFSpace, Bottom, Meet},
+};
+
+pub type PersistentSignTFSpace = PersistentTFSpace<u32, SignPowerSet>;
+
+/// Maps every key below `n` to a sign depending on the key and `seed`.
+fn sample(n: u32, seed: u32) -> PersistentSignTFSpace
+{
+	let mut f = PersistentSignTFSpace::bottom();
+	for k in 0..n {
+		f[k] = sign_of(k, seed);
+	}
+	f
+}
+
+fn sign_of(k: u32, seed: u32) -> SignPowerSet
+{
+	match (k + seed) % 4 {
+		0 => SignPowerSet::bottom(),
+		1 => SignPowerSet::singleton(Sign::Plus),
+		2 => SignPowerSet::singleton(Sign::Minus),
+		_ => SignPowerSet::from_iter(vec![Sign::Zero, Sign::Plus]),
+	}
+}
+
+#[test]
+fn index_test(){
+	let f = sample(1000, 0);
+	assert_eq!(f.keys().count(), 1000);
+	for k in 0..1000 {
+		assert_eq!(f[k], sign_of(k, 0));
+	}
+	assert!(!f.has_key(1000));
+	assert!(f.get(1000).is_bottom());
+}
+
+#[test]
+fn sharing_test(){
+	let f = sample(1000, 0);
+	let mut g = f.clone();
+	assert!(g.ptr_eq(&f));
+	assert_eq!(g, f);
+	
+	g[500] = SignPowerSet::top();
+	assert!(!g.ptr_eq(&f));
+	assert_eq!(f[500], sign_of(500, 0));
+	assert!(f < g);
+	assert!(!g.join_assign(&f));
+	
+	let mut h = f.clone();
+	assert!(h.join_assign(&g));
+	assert_eq!(h, g);
+	assert!(!h.join_assign(&g));
+}
+
+#[test]
+fn matches_hash_tf_space_test(){
+	let f1 = sample(300, 1);
+	let f2 = sample(200, 2);
+	let mut h1 = HashTFSpace::bottom();
+	let mut h2 = HashTFSpace::bottom();
+	for k in 0..300 {
+		h1[k] = f1[k].clone();
+	}
+	for k in 0..200 {
+		h2[k] = f2[k].clone();
+	}
+	
+	let joined = f1.clone() + &f2;
+	let h_joined = h1.clone() + &h2;
+	for k in 0..300 {
+		assert_eq!(joined[k], h_joined[k]);
+	}
+	assert!(f1 < joined && f2 < joined);
+	assert_eq!(f1.partial_cmp(&f2), h1.partial_cmp(&h2));
+	
+	let met = joined.clone().meet(&f2);
+	assert_eq!(met, f2);
+	assert_eq!(joined.clone().meet(&f1), f1);
+	
+	// Keys with bottom values are equal to missing keys
+	let mut f3 = f2.clone();
+	f3[1000] = SignPowerSet::bottom();
+	assert_eq!(f3, f2);
+}
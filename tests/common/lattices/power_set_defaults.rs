@@ -0,0 +1,108 @@
+use super::*;
+
+use progysis::{
+	common::lattices::HashPowerSet,
+	core::{CompleteLattice, Bottom, PowerSet},
+};
+use std::{
+	borrow::Cow,
+	ops::{Add, AddAssign},
+};
+
+/// Only implements the required `PowerSet` items, relying on the defaults for the rest.
+#[derive(Clone, PartialEq, PartialOrd, Debug)]
+struct Minimal(HashPowerSet<u32>);
+
+impl CompleteLattice for Minimal
+{
+	fn is_bottom(&self) -> bool
+	{
+		self.0.is_bottom()
+	}
+}
+
+impl Bottom for Minimal
+{
+	fn bottom() -> Self
+	{
+		Minimal(HashPowerSet::bottom())
+	}
+}
+
+impl Add for Minimal
+{
+	type Output = Self;
+	fn add(mut self, rhs: Self) -> Self::Output
+	{
+		self += rhs;
+		self
+	}
+}
+
+impl<'a> Add<&'a Self> for Minimal
+{
+	type Output = Self;
+	fn add(mut self, rhs: &'a Self) -> Self::Output
+	{
+		self += rhs;
+		self
+	}
+}
+
+impl AddAssign for Minimal
+{
+	fn add_assign(&mut self, rhs: Self)
+	{
+		self.0 += rhs.0;
+	}
+}
+
+impl<'a> AddAssign<&'a Self> for Minimal
+{
+	fn add_assign(&mut self, rhs: &'a Self)
+	{
+		self.0 += &rhs.0;
+	}
+}
+
+impl PowerSet for Minimal
+{
+	type Item = u32;
+	type All = Vec<u32>;
+	type Iter<'a> = std::vec::IntoIter<Cow<'a, u32>>;
+	
+	fn singleton(s: u32) -> Self
+	{
+		Minimal(HashPowerSet::singleton(s))
+	}
+	
+	fn all(&self) -> Vec<u32>
+	{
+		self.0.all().into_iter().collect()
+	}
+	
+	fn iter(&self) -> Self::Iter<'_>
+	{
+		self.0.iter().collect::<Vec<_>>().into_iter()
+	}
+}
+
+#[test]
+fn power_set_defaults_test()
+{
+	let mut set = Minimal::from_iter(vec![1, 2]);
+	assert!(set.contains(&1) && !set.contains(&3));
+	assert_eq!(set.len(), 2);
+	
+	assert!(set.insert(3));
+	assert!(!set.insert(3));
+	assert_eq!(set, Minimal::from_iter(vec![1, 2, 3]));
+	
+	assert!(set.remove(&1));
+	assert!(!set.remove(&1));
+	assert_eq!(set, Minimal::from_iter(vec![2, 3]));
+	
+	set.remove(&2);
+	set.remove(&3);
+	assert!(set.is_empty() && set.is_bottom());
+}
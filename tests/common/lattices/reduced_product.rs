@@ -0,0 +1,114 @@
+use super::*;
+
+use progysis::{
+	common::lattices::{Interval, IntervalBound},
+	core::{CompleteLattice, Bottom, Top, Meet, PowerSet, Reduction, ReducedProduct},
+	testing::assert_lattice_laws,
+};
+use crate::common::lattices::sign_power_set::Sign::*;
+
+pub struct IntervalSignReduction;
+
+impl Reduction<Interval, SignPowerSet> for IntervalSignReduction
+{
+	fn reduce(interval: &mut Interval, signs: &mut SignPowerSet)
+	{
+		// Remove the signs of values outside the interval
+		if let (Some(lower), Some(upper)) = (interval.lower(), interval.upper()) {
+			if lower >= IntervalBound::Int(0) {
+				signs.remove(&Minus);
+			}
+			if !interval.contains(0) {
+				signs.remove(&Zero);
+			}
+			if upper <= IntervalBound::Int(0) {
+				signs.remove(&Plus);
+			}
+		}
+		
+		// Shrink the interval to the values with the remaining signs
+		let lower = if signs.contains(&Minus) {
+			IntervalBound::NegInf
+		} else if signs.contains(&Zero) {
+			IntervalBound::Int(0)
+		} else {
+			IntervalBound::Int(1)
+		};
+		let upper = if signs.contains(&Plus) {
+			IntervalBound::PosInf
+		} else if signs.contains(&Zero) {
+			IntervalBound::Int(0)
+		} else {
+			IntervalBound::Int(-1)
+		};
+		interval.meet_assign(&Interval::from_bounds(lower, upper));
+		
+		if interval.is_bottom() || signs.is_bottom() {
+			*interval = Interval::bottom();
+			*signs = SignPowerSet::bottom();
+		}
+	}
+}
+
+pub type IntervalSign = ReducedProduct<Interval, SignPowerSet, IntervalSignReduction>;
+
+#[test]
+fn reduce_test(){
+	let p = IntervalSign::new(Interval::new(-5, 5), SignPowerSet::singleton(Plus));
+	assert_eq!(p.first(), &Interval::new(1, 5));
+	assert_eq!(p.second(), &SignPowerSet::singleton(Plus));
+	
+	let p = IntervalSign::new(Interval::at_least(0), SignPowerSet::top());
+	assert_eq!(p.into_parts(), (Interval::at_least(0), SignPowerSet::from_iter(vec![Plus, Zero])));
+	
+	// Contradicting components give bottom
+	let p = IntervalSign::new(Interval::constant(3), SignPowerSet::singleton(Minus));
+	assert!(p.is_bottom());
+	assert_eq!(p, IntervalSign::bottom());
+	
+	let top = IntervalSign::top();
+	assert!(top.is_top());
+	assert!(p < top);
+}
+
+#[test]
+fn join_test(){
+	let one = IntervalSign::new(Interval::constant(1), SignPowerSet::top());
+	let three = IntervalSign::new(Interval::constant(3), SignPowerSet::top());
+	let minus_one = IntervalSign::new(Interval::constant(-1), SignPowerSet::top());
+	
+	// Joining the intervals includes zero, but the signs show it can't occur
+	let joined = one.clone() + &minus_one;
+	assert_eq!(joined.first(), &Interval::new(-1, 1));
+	assert_eq!(joined.second(), &SignPowerSet::from_iter(vec![Plus, Minus]));
+	
+	let mut joined = one.clone();
+	assert!(joined.join_assign(&three));
+	assert_eq!(joined, IntervalSign::new(Interval::new(1, 3), SignPowerSet::singleton(Plus)));
+	assert!(!joined.join_assign(&one));
+	assert!(one < joined);
+	
+	// Meeting the signs shrinks the interval
+	let non_negative = IntervalSign::new(Interval::top(), SignPowerSet::from_iter(vec![Plus, Zero]));
+	let non_positive = IntervalSign::new(Interval::top(), SignPowerSet::from_iter(vec![Minus, Zero]));
+	assert_eq!(non_negative.meet(&non_positive), IntervalSign::new(Interval::constant(0), SignPowerSet::top()));
+}
+
+#[test]
+fn laws_test(){
+	let intervals = [
+		Interval::bottom(), Interval::constant(0), Interval::constant(2), Interval::new(-3, 4),
+		Interval::at_least(0), Interval::at_most(-1), Interval::top(),
+	];
+	let signs = [
+		SignPowerSet::singleton(Plus), SignPowerSet::singleton(Zero),
+		SignPowerSet::from_iter(vec![Minus, Zero]), SignPowerSet::top(),
+	];
+	let mut samples = Vec::new();
+	for i in intervals.iter() {
+		for s in signs.iter() {
+			samples.push(IntervalSign::new(*i, s.clone()));
+		}
+	}
+	assert_lattice_laws(&samples);
+}
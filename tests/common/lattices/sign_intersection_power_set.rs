@@ -0,0 +1,70 @@
+use super::*;
+
+use progysis::{
+	common::lattices::HashIntersectionPowerSet,
+	core::{CompleteLattice, PowerSet, Bottom, Top, Meet},
+};
+use crate::common::lattices::sign_power_set::Sign::*;
+
+pub type SignIntersectionPowerSet = HashIntersectionPowerSet<Sign>;
+
+#[test]
+fn bottom_top_test(){
+	let bottom = SignIntersectionPowerSet::bottom();
+	let top = SignIntersectionPowerSet::top();
+	assert!(bottom.is_bottom());
+	assert!(top.is_top());
+	assert_eq!(bottom, SignIntersectionPowerSet::from_iter(vec![Plus, Minus, Zero]));
+	assert_eq!(top, SignIntersectionPowerSet::from_iter(vec![]));
+	assert!(bottom < top, "{:?} >= {:?}", bottom, top);
+}
+
+#[test]
+fn comparison_test(){
+	let plus = SignIntersectionPowerSet::singleton(Plus);
+	let minus = SignIntersectionPowerSet::singleton(Minus);
+	let plus_minus = SignIntersectionPowerSet::from_iter(vec![Plus, Minus]);
+	
+	assert!(plus_minus < plus, "{:?} >= {:?}", plus_minus, plus);
+	assert!(plus_minus < minus, "{:?} >= {:?}", plus_minus, minus);
+	assert!(!plus.comparable_to(&minus));
+	assert_eq!(plus, SignIntersectionPowerSet::singleton(Plus));
+}
+
+#[test]
+fn addition_test(){
+	let bottom = SignIntersectionPowerSet::bottom();
+	let plus = SignIntersectionPowerSet::singleton(Plus);
+	let minus = SignIntersectionPowerSet::singleton(Minus);
+	let plus_minus = SignIntersectionPowerSet::from_iter(vec![Plus, Minus]);
+	let plus_zero = SignIntersectionPowerSet::from_iter(vec![Plus, Zero]);
+	
+	assert_eq!(plus_minus.clone() + plus_zero.clone(), plus);
+	assert_eq!(plus.clone() + minus.clone(), SignIntersectionPowerSet::top());
+	assert_eq!(bottom.clone() + plus_zero.clone(), plus_zero);
+	
+	let mut v = plus_zero.clone();
+	v += &plus_minus;
+	assert_eq!(v, plus);
+}
+
+#[test]
+fn meet_test(){
+	let plus = SignIntersectionPowerSet::singleton(Plus);
+	let minus = SignIntersectionPowerSet::singleton(Minus);
+	let plus_minus = SignIntersectionPowerSet::from_iter(vec![Plus, Minus]);
+	
+	assert_eq!(plus.clone().meet(&minus), plus_minus);
+	assert_eq!(SignIntersectionPowerSet::top().meet(&plus), plus);
+}
+
+#[test]
+fn set_operations_test(){
+	let mut v = SignIntersectionPowerSet::bottom();
+	assert_eq!(v.len(), 3);
+	assert!(v.remove(&Zero));
+	assert!(!v.contains(&Zero));
+	assert!(v > SignIntersectionPowerSet::bottom());
+	assert!(v.insert(Zero));
+	assert!(v.is_bottom());
+}
@@ -9,20 +9,27 @@ use std::{
 	cmp::Ordering,
 };
 use progysis::{
-	common::lattices::{HashPowerSet},
-	core::{CompleteLattice},
+	common::lattices::{Flat},
+	core::{CompleteLattice, Top, Meet},
 };
+pub use progysis::common::lattices::{Sign, SignPowerSet};
 use crate::common::lattices::sign_power_set::Sign::*;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum Sign{
-	Plus,
-	Zero,
-	Minus
+#[test]
+fn top_test(){
+	let top = SignPowerSet::top();
+	assert!(top.is_top());
+	assert_eq!(top, SignPowerSet::from_iter(vec![Plus, Minus, Zero]));
+	
+	let all = vec![SignPowerSet::bottom(), SignPowerSet::singleton(Plus),
+				   SignPowerSet::from_iter(vec![Minus, Zero])];
+	for e in all {
+		assert!(!e.is_top(), "{:?}", e);
+		assert!(e < top, "{:?} >= {:?}", e, top);
+		assert_eq!(e + top.clone(), top);
+	}
 }
 
-pub type SignPowerSet = HashPowerSet<Sign>;
-
 #[test]
 fn comparison_test(){
 	let empty =SignPowerSet::bottom();
@@ -215,4 +222,108 @@ fn addAssign_test()
 	addAssign_equals!(zero.clone(), plus_minus.clone() => plus_minus_zero.clone());
 	addAssign_equals!(zero.clone(), plus_zero.clone() => plus_zero.clone());
 	addAssign_equals!(zero.clone(), minus_zero.clone() => minus_zero.clone());
-}
\ No newline at end of file
+}
+
+#[test]
+fn meet_test()
+{
+	let empty = SignPowerSet::bottom();
+	let plus = SignPowerSet::singleton(Plus);
+	let minus = SignPowerSet::singleton(Minus);
+	let plus_minus = SignPowerSet::from_iter(vec![Plus, Minus]);
+	let plus_zero = SignPowerSet::from_iter(vec![Plus, Zero]);
+	let top = SignPowerSet::top();
+	
+	assert_eq!(plus.clone().meet(&minus), empty);
+	assert_eq!(plus_minus.clone().meet(&plus_zero), plus);
+	assert_eq!(plus_minus.clone().meet(&top), plus_minus);
+	assert_eq!(top.clone().meet(&empty), empty);
+	
+	let mut v = plus_zero.clone();
+	v.meet_assign(&plus_minus);
+	assert_eq!(v, plus);
+}
+
+#[test]
+fn arithmetic_test()
+{
+	let empty = SignPowerSet::bottom();
+	let plus = SignPowerSet::singleton(Plus);
+	let zero = SignPowerSet::singleton(Zero);
+	let minus = SignPowerSet::singleton(Minus);
+	let plus_zero = SignPowerSet::from_iter(vec![Plus, Zero]);
+	let minus_zero = SignPowerSet::from_iter(vec![Minus, Zero]);
+	let top = SignPowerSet::top();
+	
+	assert_eq!(plus.plus(&plus), plus);
+	assert_eq!(plus.plus(&zero), plus);
+	assert_eq!(plus.plus(&minus), top);
+	assert_eq!(plus_zero.plus(&zero), plus_zero);
+	assert_eq!(plus.minus(&minus), plus);
+	assert_eq!(minus.increment(), minus_zero);
+	assert_eq!(zero.increment(), plus);
+	assert_eq!(minus_zero.increment(), top);
+	assert_eq!(plus_zero.increment(), plus);
+	assert_eq!(empty.increment(), empty);
+	assert_eq!(plus_zero.negate(), minus_zero);
+	assert_eq!(minus.times(&minus), plus);
+	assert_eq!(minus.times(&plus_zero), minus_zero);
+	assert_eq!(plus.divide(&plus), plus_zero);
+	assert_eq!(minus.divide(&plus), minus_zero);
+	assert_eq!(plus.divide(&zero), empty);
+	assert_eq!(empty.plus(&plus), empty);
+	assert_eq!(Sign::of(-3), Minus);
+	assert_eq!(Sign::of(0), Zero);
+}
+
+#[test]
+fn comparison_operations_test()
+{
+	let plus = SignPowerSet::singleton(Plus);
+	let zero = SignPowerSet::singleton(Zero);
+	let minus = SignPowerSet::singleton(Minus);
+	let plus_zero = SignPowerSet::from_iter(vec![Plus, Zero]);
+	
+	assert_eq!(minus.less_than(&plus_zero), Flat::Value(true));
+	assert_eq!(zero.less_than(&zero), Flat::Value(false));
+	assert_eq!(zero.less_equal(&zero), Flat::Value(true));
+	assert_eq!(plus.less_than(&plus), Flat::Top);
+	assert_eq!(plus.greater_than(&zero), Flat::Value(true));
+	assert_eq!(minus.greater_equal(&zero), Flat::Value(false));
+	assert_eq!(zero.equal_to(&zero), Flat::Value(true));
+	assert_eq!(plus.equal_to(&minus), Flat::Value(false));
+	assert_eq!(plus_zero.not_equal(&zero), Flat::Top);
+	assert_eq!(plus.less_than(&SignPowerSet::bottom()), Flat::Bottom);
+}
+#[test]
+fn set_operations_test()
+{
+	let mut v = SignPowerSet::from_iter(vec![Plus, Minus]);
+	assert_eq!(v.len(), 2);
+	assert!(v.contains(&Plus));
+	assert!(!v.contains(&Zero));
+	assert_eq!(v.iter().map(|s| *s).collect::<HashSet<_>>(),
+			   HashSet::from_iter(vec![Plus, Minus]));
+	
+	assert!(v.insert(Zero));
+	assert!(!v.insert(Zero));
+	assert!(v.is_top());
+	assert!(v.remove(&Plus));
+	assert!(!v.remove(&Plus));
+	assert_eq!(v, SignPowerSet::from_iter(vec![Zero, Minus]));
+	
+	v.remove(&Zero);
+	v.remove(&Minus);
+	assert!(v.is_empty());
+	assert!(v.is_bottom());
+}
+
+#[test]
+fn join_assign_test()
+{
+	let mut v = SignPowerSet::singleton(Plus);
+	assert!(!v.join_assign(&SignPowerSet::bottom()));
+	assert!(!v.join_assign(&SignPowerSet::singleton(Plus)));
+	assert!(v.join_assign(&SignPowerSet::from_iter(vec![Plus, Zero])));
+	assert_eq!(v, SignPowerSet::from_iter(vec![Plus, Zero]));
+}
@@ -1,8 +1,11 @@
 use super::*;
 
 use std::panic::catch_unwind;
-use progysis::common::lattices::{
-	HashTFSpace, HashPowerSet
+use progysis::{
+	common::lattices::{
+		HashTFSpace, HashPowerSet
+	},
+	core::Meet,
 };
 
 pub type StringSignTFSpace<'a> = HashTFSpace<&'a str, SignPowerSet>;
@@ -21,6 +24,15 @@ fn add_key_test(){
 	assert_eq!( f["v1"], HashPowerSet::bottom(), "\nf: {:?}", f);
 }
 
+#[test]
+fn get_test(){
+	let mut f = StringSignTFSpace::bottom();
+	assert!(f.get("v1").is_bottom());
+	assert!(!f.has_key("v1"));
+	f["v1"] = SignPowerSet::singleton(Sign::Plus);
+	assert_eq!(*f.get("v1"), SignPowerSet::singleton(Sign::Plus));
+}
+
 #[test]
 fn comparison_test(){
 	let mut f1 = StringSignTFSpace::bottom();
@@ -60,3 +72,38 @@ fn add_assign_test(){
 	f1 += f2;
 	assert_eq!( f3, f1, "{:?} != {:?}", f3, f1);
 }
+
+#[test]
+fn meet_test(){
+	let mut f1 = StringSignTFSpace::bottom();
+	let mut f2 = StringSignTFSpace::bottom();
+	f1["v1"] = HashPowerSet::from_iter(vec![Sign::Zero, Sign::Plus]);
+	f1["v2"] = HashPowerSet::singleton(Sign::Minus);
+	f2["v1"] = HashPowerSet::from_iter(vec![Sign::Zero, Sign::Minus]);
+	let f3 = f1.clone().meet(&f2);
+	assert_eq!( f3["v1"], HashPowerSet::singleton(Sign::Zero), "\nf3: {:?}", f3);
+	assert!(f3.get("v2").is_bottom(), "\nf3: {:?}", f3);
+	assert!( f3 <= f1 && f3 <= f2, "\nf3: {:?}", f3);
+}
+
+#[test]
+fn join_assign_test(){
+	let mut f = StringSignTFSpace::bottom();
+	f["x"] = SignPowerSet::singleton(Sign::Plus);
+	
+	let mut g = StringSignTFSpace::bottom();
+	g["x"] = SignPowerSet::singleton(Sign::Plus);
+	g["y"] = SignPowerSet::bottom();
+	
+	// Adding a key with a bottom value is not a change
+	assert!(!f.join_assign(&g));
+	
+	g["y"] = SignPowerSet::singleton(Sign::Zero);
+	assert!(f.join_assign(&g));
+	assert_eq!(f, g);
+	assert!(!f.join_assign(&g));
+	
+	g["x"] = SignPowerSet::singleton(Sign::Minus);
+	assert!(f.join_assign(&g));
+	assert_eq!(f["x"], SignPowerSet::from_iter(vec![Sign::Plus, Sign::Minus]));
+}
@@ -0,0 +1,79 @@
+use super::*;
+
+use progysis::{
+	common::lattices::Interval,
+	core::{CompleteLattice, Bottom, Top, Meet, Sum},
+	testing::assert_lattice_laws,
+};
+use crate::common::lattices::sign_power_set::Sign::*;
+
+pub type IntervalOrSigns = Sum<Interval, SignPowerSet>;
+
+#[test]
+fn bottom_top_test(){
+	let bottom = IntervalOrSigns::bottom();
+	assert!(bottom.is_bottom());
+	assert!(IntervalOrSigns::top().is_top());
+	
+	// The bottoms of both sides are the shared bottom
+	assert_eq!(bottom, Sum::Left(Interval::bottom()));
+	assert_eq!(bottom, Sum::Right(SignPowerSet::bottom()));
+	assert!(Sum::Right(SignPowerSet::top()) < IntervalOrSigns::top());
+}
+
+#[test]
+fn join_test(){
+	let i1: IntervalOrSigns = Sum::Left(Interval::constant(1));
+	let i5 = Sum::Left(Interval::constant(5));
+	let plus = Sum::Right(SignPowerSet::singleton(Plus));
+	let minus = Sum::Right(SignPowerSet::singleton(Minus));
+	
+	assert_eq!(i1.clone() + &i5, Sum::Left(Interval::new(1, 5)));
+	assert_eq!(plus.clone() + minus.clone(), Sum::Right(SignPowerSet::from_iter(vec![Plus, Minus])));
+	assert_eq!(i1.clone() + &plus, Sum::Top);
+	assert_eq!(i1.clone() + IntervalOrSigns::bottom(), i1);
+	assert_eq!(Sum::Right(SignPowerSet::bottom()) + &i1, i1);
+	assert!(!i1.comparable_to(&plus));
+	
+	assert_eq!(i1.clone().meet(&plus), IntervalOrSigns::bottom());
+	assert_eq!(IntervalOrSigns::top().meet(&plus), plus);
+	assert_eq!(Sum::Left(Interval::new(0, 3)).meet(&i1), i1);
+}
+
+#[test]
+fn accessor_test(){
+	let mut i1: IntervalOrSigns = Sum::Left(Interval::constant(1));
+	assert_eq!(i1.left_ref(), Some(&Interval::constant(1)));
+	assert_eq!(i1.right_ref(), None);
+	*i1.left_ref_mut().unwrap() += Interval::constant(2);
+	assert_eq!(i1.clone().left(), Some(Interval::new(1, 2)));
+	assert_eq!(IntervalOrSigns::top().right(), None);
+	
+	// Bottom is never a left or right element
+	let mut empty: IntervalOrSigns = Sum::Left(Interval::bottom());
+	assert_eq!(empty.left_ref(), None);
+	assert_eq!(empty.left_ref_mut(), None);
+	assert_eq!(empty.clone().left(), IntervalOrSigns::bottom().left());
+	assert_eq!(Sum::<Interval, _>::Right(SignPowerSet::bottom()).right_ref(), None);
+}
+
+#[test]
+fn normalize_test(){
+	let i1: IntervalOrSigns = Sum::Left(Interval::constant(1));
+	let i5 = Sum::Left(Interval::constant(5));
+	
+	assert!(matches!(i1.clone().meet(&i5), Sum::Bottom));
+	assert!(matches!(Sum::Left(Interval::bottom()) + IntervalOrSigns::bottom(), Sum::Bottom));
+	assert!(matches!(IntervalOrSigns::bottom() + &Sum::Right(SignPowerSet::bottom()), Sum::Bottom));
+}
+
+#[test]
+fn laws_test(){
+	assert_lattice_laws(&[
+		IntervalOrSigns::bottom(), IntervalOrSigns::top(),
+		Sum::Left(Interval::bottom()), Sum::Right(SignPowerSet::bottom()),
+		Sum::Left(Interval::constant(1)), Sum::Left(Interval::new(-1, 4)), Sum::Left(Interval::top()),
+		Sum::Right(SignPowerSet::singleton(Plus)), Sum::Right(SignPowerSet::singleton(Zero)),
+		Sum::Right(SignPowerSet::top()),
+	]);
+}
@@ -0,0 +1,75 @@
+use super::*;
+
+use progysis::{
+	common::lattices::VecTFSpace,
+	core::{CompleteLattice, TFSpace, Bottom, Meet},
+};
+
+pub type VecSignTFSpace = VecTFSpace<SignPowerSet>;
+
+#[test]
+fn index_test(){
+	let mut f = VecSignTFSpace::bottom();
+	assert!(!f.has_key(3));
+	assert!(f.get(3).is_bottom());
+	
+	f[3] = SignPowerSet::singleton(Sign::Plus);
+	assert_eq!(f.keys().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+	assert!(f[1].is_bottom());
+	assert_eq!(*f.get(3), SignPowerSet::singleton(Sign::Plus));
+}
+
+#[test]
+fn add_key_test(){
+	let mut f = VecSignTFSpace::bottom();
+	f.add_key(1);
+	assert_eq!(f.keys().collect::<Vec<_>>(), vec![0, 1]);
+	assert!(f[1].is_bottom());
+	
+	// Like the other TFSpaces, adding a present key resets it to bottom
+	f[1] = SignPowerSet::singleton(Sign::Plus);
+	f[0] = SignPowerSet::singleton(Sign::Minus);
+	f.add_key(1);
+	assert!(f[1].is_bottom());
+	assert_eq!(f[0], SignPowerSet::singleton(Sign::Minus));
+}
+
+#[test]
+fn comparison_test(){
+	let mut f1 = VecSignTFSpace::bottom();
+	let mut f2 = VecSignTFSpace::bottom();
+	f1[0] = SignPowerSet::singleton(Sign::Plus);
+	f2[0] = SignPowerSet::singleton(Sign::Plus);
+	
+	// Trailing bottom values don't matter
+	f2[5] = SignPowerSet::bottom();
+	assert_eq!(f1, f2);
+	
+	f2[5] = SignPowerSet::singleton(Sign::Zero);
+	assert!(f1 < f2);
+	assert!(f2 > f1);
+	
+	f1[1] = SignPowerSet::singleton(Sign::Minus);
+	assert!(!f1.comparable_to(&f2));
+}
+
+#[test]
+fn join_test(){
+	let mut f1 = VecSignTFSpace::bottom();
+	let mut f2 = VecSignTFSpace::bottom();
+	f1[0] = SignPowerSet::singleton(Sign::Plus);
+	f2[0] = SignPowerSet::singleton(Sign::Minus);
+	f2[2] = SignPowerSet::singleton(Sign::Zero);
+	
+	let f3 = f1.clone() + &f2;
+	assert_eq!(f3[0], SignPowerSet::from_iter(vec![Sign::Plus, Sign::Minus]));
+	assert_eq!(f3[2], SignPowerSet::singleton(Sign::Zero));
+	assert!(f1 < f3 && f2 < f3);
+	
+	let mut f4 = f1.clone();
+	assert!(f4.join_assign(&f2));
+	assert_eq!(f4, f3);
+	assert!(!f4.join_assign(&f2));
+	
+	assert_eq!(f3.meet(&f2), f2);
+}
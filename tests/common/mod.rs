@@ -1,4 +1,7 @@
 use super::*;
 
 mod lattices;
-mod solve;
\ No newline at end of file
+mod solve;
+#[cfg(feature = "derive")]
+mod derive;
+mod testing;
\ No newline at end of file
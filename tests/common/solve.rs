@@ -4,10 +4,12 @@ use crate::common::{
 		Sign, SignPowerSet, StringSignTFSpace
 	}
 };
+use progysis::common::lattices::Interval;
 use progysis::{
+	product_lattice,
 	common::worklist::FifoWorklist,
 	core::{
-		CompleteLattice, Analysis, SubLattice, PowerSet, TFSpace, U32, U64, Bottom
+		CompleteLattice, Analysis, SubLattice, PowerSet, TFSpace, U32, U64, Bottom, Top, Meet, Lift, Dual
 	}
 };
 use graphene::{
@@ -17,7 +19,7 @@ use graphene::{
 	common::AdjListGraph
 };
 use std::{
-	collections::HashMap,
+	collections::{HashMap, HashSet},
 	marker::PhantomData,
 	ops::{Add,AddAssign},
 	hash::Hash
@@ -38,7 +40,7 @@ impl<G,L> Analysis<G,L> for U32Analysis
 	
 	fn transfer(e: &L, _: &L, action: &G::EdgeWeight) -> Self::Lattice
 	{
-		U32(e.sub_lattice_ref().0 + action)
+		U32(e.sub_lattice_ref().0.saturating_add(*action))
 	}
 }
 
@@ -64,6 +66,223 @@ fn solve_test()
 	assert_eq!(U32(4), map[&v2]);
 }
 
+struct LiftedU32Analysis {}
+
+impl<G,L> Analysis<G,L> for LiftedU32Analysis
+	where
+		G: Graph<Directedness=Directed, EdgeWeight=u32>,
+		G::Vertex: Hash,
+		L: Bottom + SubLattice<Lift<U32>>
+{
+	type Lattice = Lift<U32>;
+	const FORWARD: bool = true;
+	
+	fn transfer(e: &L, _: &L, action: &G::EdgeWeight) -> Self::Lattice
+	{
+		e.sub_lattice_ref().map(|v| U32(v.0.saturating_add(*action)))
+	}
+}
+
+#[test]
+fn solve_lift_test()
+{
+	let mut program = AdjListGraph::<(),_>::new();
+	let v0 = program.new_vertex().unwrap();
+	let v1 = program.new_vertex().unwrap();
+	let v2 = program.new_vertex().unwrap();
+	let v3 = program.new_vertex().unwrap();
+	
+	program.add_edge_weighted((v0,v1,1)).unwrap();
+	program.add_edge_weighted((v2,v3,2)).unwrap();
+	program.add_edge_weighted((v3,v2,2)).unwrap();
+	
+	let mut map = HashMap::new();
+	map.insert(v0, Lift::reachable(U32(0)));
+	LiftedU32Analysis::analyze::<FifoWorklist<_>>(&program, &mut map);
+	
+	assert_eq!(Lift::reachable(U32(0)), map[&v0]);
+	assert_eq!(Lift::reachable(U32(1)), map[&v1]);
+	assert!(!map[&v2].is_reachable());
+	assert!(!map[&v3].is_reachable());
+}
+
+#[test]
+fn solve_lift_forwarding_test()
+{
+	// The analysis only knows the wrapped lattice, so it accesses the states through SubLattice
+	let mut program = AdjListGraph::<(),_>::new();
+	let v0 = program.new_vertex().unwrap();
+	let v1 = program.new_vertex().unwrap();
+	let v2 = program.new_vertex().unwrap();
+	
+	program.add_edge_weighted((v0,v1,1)).unwrap();
+	
+	let mut map = HashMap::new();
+	map.insert(v0, Lift::reachable(U32(3)));
+	U32Analysis::analyze::<FifoWorklist<_>>(&program, &mut map);
+	
+	assert_eq!(Lift::reachable(U32(3)), map[&v0]);
+	assert_eq!(Lift::reachable(U32(4)), map[&v1]);
+	assert!(!map[&v2].is_reachable());
+	assert_eq!(Lift::unreachable(), map[&v2]);
+}
+
+/// The signs that are certain to have been seen on every path, where each edge sees the
+/// sign of its weight.
+struct MustSeeSignAnalysis {}
+
+impl<G,L> Analysis<G,L> for MustSeeSignAnalysis
+	where
+		G: Graph<Directedness=Directed, EdgeWeight=u32>,
+		G::Vertex: Hash,
+		L: Bottom + SubLattice<Dual<SignPowerSet>>
+{
+	type Lattice = Dual<SignPowerSet>;
+	const FORWARD: bool = true;
+	
+	fn transfer(e: &L, _: &L, action: &G::EdgeWeight) -> Self::Lattice
+	{
+		Dual(e.sub_lattice_ref().inner().clone() + SignPowerSet::singleton(Sign::of(*action as i64)))
+	}
+}
+
+#[test]
+fn solve_dual_test()
+{
+	use self::Sign::*;
+	let mut program = AdjListGraph::<(),_>::new();
+	let v0 = program.new_vertex().unwrap();
+	let v1 = program.new_vertex().unwrap();
+	let v2 = program.new_vertex().unwrap();
+	let v3 = program.new_vertex().unwrap();
+	
+	program.add_edge_weighted((v0,v1,1)).unwrap();
+	program.add_edge_weighted((v0,v2,0)).unwrap();
+	program.add_edge_weighted((v1,v3,0)).unwrap();
+	program.add_edge_weighted((v2,v3,0)).unwrap();
+	program.add_edge_weighted((v3,v1,1)).unwrap();
+	
+	let mut map = HashMap::new();
+	map.insert(v0, Dual(SignPowerSet::bottom()));
+	MustSeeSignAnalysis::analyze::<FifoWorklist<_>>(&program, &mut map);
+	
+	// The greatest fixed point, where merging paths intersects the seen signs
+	assert_eq!(Dual(SignPowerSet::bottom()), map[&v0]);
+	assert_eq!(Dual(SignPowerSet::singleton(Plus)), map[&v1]);
+	assert_eq!(Dual(SignPowerSet::singleton(Zero)), map[&v2]);
+	assert_eq!(Dual(SignPowerSet::singleton(Zero)), map[&v3]);
+}
+
+#[test]
+fn solve_widening_test()
+{
+	let mut program = AdjListGraph::<(),_>::new();
+	let v0 = program.new_vertex().unwrap();
+	let v1 = program.new_vertex().unwrap();
+	let v2 = program.new_vertex().unwrap();
+	let v3 = program.new_vertex().unwrap();
+	
+	program.add_edge_weighted((v0,v1,1)).unwrap();
+	program.add_edge_weighted((v1,v2,2)).unwrap();
+	program.add_edge_weighted((v2,v1,1)).unwrap();
+	program.add_edge_weighted((v0,v3,3)).unwrap();
+	
+	let mut widening_points = HashSet::new();
+	widening_points.insert(v1);
+	
+	let mut map = HashMap::new();
+	map.insert(v0, U32(1));
+	U32Analysis::analyze_widening::<FifoWorklist<_>>(&program, &mut map, &widening_points);
+	
+	assert_eq!(U32(1), map[&v0]);
+	assert_eq!(U32::top(), map[&v1]);
+	assert_eq!(U32::top(), map[&v2]);
+	assert_eq!(U32(4), map[&v3]);
+}
+
+#[test]
+fn solve_widening_acyclic_test()
+{
+	let mut program = AdjListGraph::<(),_>::new();
+	let v0 = program.new_vertex().unwrap();
+	let v1 = program.new_vertex().unwrap();
+	let v2 = program.new_vertex().unwrap();
+	
+	program.add_edge_weighted((v0,v1,1)).unwrap();
+	program.add_edge_weighted((v1,v2,2)).unwrap();
+	
+	let mut widening_points = HashSet::new();
+	widening_points.insert(v1);
+	
+	let mut map = HashMap::new();
+	map.insert(v0, U32(1));
+	U32Analysis::analyze_widening::<FifoWorklist<_>>(&program, &mut map, &widening_points);
+	
+	assert_eq!(U32(1), map[&v0]);
+	assert_eq!(U32(2), map[&v1]);
+	assert_eq!(U32(4), map[&v2]);
+}
+
+enum LoopAction{
+	AssignZero,
+	AssumeBelow(i64),
+	AssumeAtLeast(i64),
+	Increment,
+}
+
+struct IntervalAnalysis{}
+
+impl<G,L> Analysis<G,L> for IntervalAnalysis
+	where
+		G: Graph<Directedness=Directed, EdgeWeight=LoopAction>,
+		G::Vertex: Hash,
+		L: Bottom + SubLattice<Interval>
+{
+	type Lattice = Interval;
+	const FORWARD: bool = true;
+	
+	fn transfer(dependency: &L, _: &L, action: &LoopAction) -> Interval
+	{
+		let i = dependency.sub_lattice_ref();
+		if i.is_bottom() {
+			return Interval::bottom();
+		}
+		match *action {
+			LoopAction::AssignZero => Interval::constant(0),
+			LoopAction::AssumeBelow(v) => i.meet(&Interval::at_most(v - 1)),
+			LoopAction::AssumeAtLeast(v) => i.meet(&Interval::at_least(v)),
+			LoopAction::Increment => i.plus(&Interval::constant(1)),
+		}
+	}
+}
+
+#[test]
+fn solve_interval_widening_test()
+{
+	// i = 0; while i < 10 { i += 1 }
+	let mut program = AdjListGraph::<(),_>::new();
+	let v0 = program.new_vertex().unwrap();
+	let v1 = program.new_vertex().unwrap();
+	let v2 = program.new_vertex().unwrap();
+	let v3 = program.new_vertex().unwrap();
+	
+	program.add_edge_weighted((v0,v1,LoopAction::AssignZero)).unwrap();
+	program.add_edge_weighted((v1,v2,LoopAction::AssumeBelow(10))).unwrap();
+	program.add_edge_weighted((v2,v1,LoopAction::Increment)).unwrap();
+	program.add_edge_weighted((v1,v3,LoopAction::AssumeAtLeast(10))).unwrap();
+	
+	let mut widening_points = HashSet::new();
+	widening_points.insert(v1);
+	
+	let mut map = HashMap::new();
+	map.insert(v0, Interval::top());
+	IntervalAnalysis::analyze_widening::<FifoWorklist<_>>(&program, &mut map, &widening_points);
+	
+	assert_eq!(Interval::new(0, 10), map[&v1]);
+	assert_eq!(Interval::new(0, 9), map[&v2]);
+	assert_eq!(Interval::constant(10), map[&v3]);
+}
+
 enum Action{
 	DeclareX,
 	DeclareY,
@@ -94,25 +313,11 @@ impl<'a, G,L> Analysis<G,L> for SignAnalysis<'a>
 		let mut result = init.sub_lattice_ref().clone();
 		match *acc {
 			DeclareX => {
-				result["x"] = SignPowerSet::from_iter(
-					vec![Plus, Minus, Zero]
-				);
+				result["x"] = SignPowerSet::top();
 			},
 			IncX => {
-				let x = if result.has_key("x"){ result["x"].clone()}else{SignPowerSet::bottom()};
-				result["x"] =
-					if x >= SignPowerSet::singleton(Minus){
-						if x >= SignPowerSet::singleton(Zero){
-							x + SignPowerSet::singleton(Plus)
-						}else{
-							x + SignPowerSet::singleton(Zero)
-						}
-					}else if x >= SignPowerSet::singleton(Zero){
-						SignPowerSet::singleton(Plus)
-					}else{
-						x
-					}
-				;
+				let x = result.get("x").increment();
+				result["x"] = x;
 			},
 			YIsMinus1 => {
 				result["y"] =SignPowerSet::singleton(Minus);
@@ -121,9 +326,7 @@ impl<'a, G,L> Analysis<G,L> for SignAnalysis<'a>
 				result["x"] = SignPowerSet::singleton(Zero);
 			},
 			ReadY | DeclareY => {
-				result["y"] = SignPowerSet::from_iter(
-					vec![Plus, Minus, Zero]
-				);
+				result["y"] = SignPowerSet::top();
 			},
 			_ => (),
 		}
@@ -154,7 +357,7 @@ fn solve_tf_space()
 	
 	SignAnalysis::analyze::<FifoWorklist<_>>(&g, &mut initial);
 	
-	let top = SignPowerSet::from_iter(vec![Plus,Minus,Zero]);
+	let top = SignPowerSet::top();
 	let plus_zero = SignPowerSet::from_iter(vec![Plus, Zero]);
 	let minus = SignPowerSet::singleton(Minus);
 	let plus = SignPowerSet::singleton(Plus);
@@ -169,51 +372,9 @@ fn solve_tf_space()
 	assert_eq!(plus, initial[&7]["x"]);				assert_eq!(top, initial[&7]["y"]);
 }
 
-#[derive(Copy, Clone,PartialOrd, PartialEq,Debug)]
-struct D32(U64, U32);
-
-impl Bottom for D32
-{
-	fn bottom() -> Self
-	{
-		D32(U64::bottom(), U32::bottom())
-	}
-}
-
-impl SubLattice<U32> for D32
-{
-	fn sub_lattice(self) -> U32
-	{
-		self.1
-	}
-	
-	fn sub_lattice_ref(&self) -> &U32
-	{
-		&self.1
-	}
-	
-	fn sub_lattice_ref_mut(&mut self) -> &mut U32
-	{
-		&mut self.1
-	}
-}
-
-impl SubLattice<U64> for D32
-{
-	fn sub_lattice(self) -> U64
-	{
-		self.0
-	}
-	
-	fn sub_lattice_ref(&self) -> &U64
-	{
-		&self.0
-	}
-	
-	fn sub_lattice_ref_mut(&mut self) -> &mut U64
-	{
-		&mut self.0
-	}
+product_lattice!{
+	#[derive(Copy, Debug)]
+	struct D32(U64, U32);
 }
 
 struct U64Analysis{}
@@ -0,0 +1,332 @@
+use crate::common::lattices::{
+	Sign, SignPowerSet, StringSignTFSpace
+};
+use progysis::{
+	common::lattices::{Flat, Interval, BitSetPowerSet, VecTFSpace, PersistentTFSpace},
+	core::{
+		CompleteLattice, Bottom, Top, Finite, PowerSet, Dual, Lift, U32, Analysis, SubLattice,
+		Abstraction, Max, Min
+	},
+	testing::{
+		check_lattice_laws, assert_lattice_laws, check_join, Violation, check_monotone,
+		check_distributive, assert_monotone, assert_distributive,
+		assert_abstraction, check_sound, assert_sound, assert_sound_binary, Unsoundness
+	},
+};
+use graphene::{
+	core::{Graph, Directed},
+	common::AdjListGraph,
+};
+use std::{
+	cmp::min,
+	hash::Hash,
+	ops::{Add, AddAssign},
+};
+
+/// All the elements of the sign power set.
+fn sign_samples() -> Vec<SignPowerSet>
+{
+	let signs = Sign::universe();
+	let mut samples = Vec::new();
+	for mask in 0..(1 << signs.len()) {
+		samples.push(SignPowerSet::from_iter(
+			signs.iter().enumerate().filter(|(i, _)| mask & (1 << i) != 0).map(|(_, s)| *s)));
+	}
+	samples
+}
+
+#[test]
+fn sign_power_set_laws_test()
+{
+	assert_lattice_laws(&sign_samples());
+	assert_lattice_laws(&sign_samples().into_iter().map(Dual).collect::<Vec<_>>());
+	
+	let mut lifted: Vec<_> = sign_samples().into_iter().map(Lift::reachable).collect();
+	lifted.push(Lift::unreachable());
+	assert_lattice_laws(&lifted);
+}
+
+#[test]
+fn tf_space_laws_test()
+{
+	let mut hash_samples = vec![StringSignTFSpace::bottom()];
+	let mut vec_samples = vec![VecTFSpace::bottom()];
+	let mut persistent_samples = vec![PersistentTFSpace::bottom()];
+	for (i, s) in sign_samples().into_iter().enumerate() {
+		let mut h = StringSignTFSpace::bottom();
+		let mut v = VecTFSpace::bottom();
+		let mut p = PersistentTFSpace::bottom();
+		h[["x", "y"][i % 2]] = s.clone();
+		v[i % 3] = s.clone();
+		p[i % 3] = s.clone();
+		h["z"] = s.clone();
+		v[3] = s.clone();
+		p[3] = s;
+		hash_samples.push(h);
+		vec_samples.push(v);
+		persistent_samples.push(p);
+	}
+	assert_lattice_laws(&hash_samples);
+	assert_lattice_laws(&vec_samples);
+	assert_lattice_laws(&persistent_samples);
+}
+
+#[test]
+fn numeric_laws_test()
+{
+	assert_lattice_laws(&[Interval::bottom(), Interval::top(), Interval::constant(0),
+		Interval::new(-5, 3), Interval::new(2, 10), Interval::at_least(1), Interval::at_most(-1)]);
+	assert_lattice_laws(&[Flat::Bottom, Flat::Value(1), Flat::Value(2), Flat::Top]);
+	assert_lattice_laws(&[U32(0), U32(1), U32(7), U32::top()]);
+	assert_lattice_laws(&[BitSetPowerSet::bottom(), BitSetPowerSet::singleton(1usize),
+		BitSetPowerSet::singleton(100), BitSetPowerSet::from_iter(vec![1, 64, 100])]);
+}
+
+///
+/// A broken lattice that joins using the minimum instead of the maximum.
+///
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+struct MinJoin(u32);
+
+impl CompleteLattice for MinJoin
+{
+	fn is_bottom(&self) -> bool
+	{
+		self.0 == 0
+	}
+}
+
+impl Bottom for MinJoin
+{
+	fn bottom() -> Self
+	{
+		MinJoin(0)
+	}
+}
+
+impl Add for MinJoin
+{
+	type Output = Self;
+	fn add(self, rhs: Self) -> Self::Output
+	{
+		MinJoin(min(self.0, rhs.0))
+	}
+}
+
+impl<'a> Add<&'a Self> for MinJoin
+{
+	type Output = Self;
+	fn add(self, rhs: &'a Self) -> Self::Output
+	{
+		MinJoin(min(self.0, rhs.0))
+	}
+}
+
+impl AddAssign for MinJoin
+{
+	fn add_assign(&mut self, rhs: Self)
+	{
+		self.0 = min(self.0, rhs.0);
+	}
+}
+
+impl<'a> AddAssign<&'a Self> for MinJoin
+{
+	fn add_assign(&mut self, rhs: &'a Self)
+	{
+		self.0 = min(self.0, rhs.0);
+	}
+}
+
+#[test]
+fn violation_test()
+{
+	let samples = [MinJoin(0), MinJoin(1), MinJoin(2)];
+	let violations = check_lattice_laws(&samples);
+	
+	assert!(violations.contains(&Violation::new("join upper bound", vec![MinJoin(1), MinJoin(2)])));
+	assert!(violations.contains(&Violation::new("bottom is join identity", vec![MinJoin(1)])));
+	// The order itself is fine
+	assert!(violations.iter().all(|v| v.law.starts_with("join") || v.law.starts_with("bottom")));
+	assert_eq!(violations[0].to_string(), format!("{} violated by {:?}",
+		violations[0].law, violations[0].elements));
+	
+	// A single element can't violate the join laws
+	assert!(check_join(&[MinJoin(3)]).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "join upper bound violated by [MinJoin(1), MinJoin(2)]")]
+fn assert_violation_test()
+{
+	assert_lattice_laws(&[MinJoin(1), MinJoin(2)]);
+}
+
+/// Adds the edge weight, except that values above 5 wrap around to 0, which isn't monotone.
+struct WrappingAnalysis {}
+
+impl<G,L> Analysis<G,L> for WrappingAnalysis
+	where
+		G: Graph<Directedness=Directed, EdgeWeight=u32>,
+		G::Vertex: Hash,
+		L: Bottom + SubLattice<U32>
+{
+	type Lattice = U32;
+	const FORWARD: bool = true;
+	
+	fn transfer(e: &L, _: &L, action: &u32) -> U32
+	{
+		let v = e.sub_lattice_ref().0 + action;
+		U32(if v > 5 { 0 } else { v })
+	}
+}
+
+/// Sets with more than one sign are widened to top, which is monotone but not distributive.
+struct CoarseSignAnalysis {}
+
+impl<G,L> Analysis<G,L> for CoarseSignAnalysis
+	where
+		G: Graph<Directedness=Directed, EdgeWeight=u32>,
+		G::Vertex: Hash,
+		L: Bottom + SubLattice<SignPowerSet>
+{
+	type Lattice = SignPowerSet;
+	const FORWARD: bool = true;
+	
+	fn transfer(e: &L, _: &L, _: &u32) -> SignPowerSet
+	{
+		let v = e.sub_lattice_ref();
+		if v.len() > 1 { SignPowerSet::top() } else { v.clone() }
+	}
+}
+
+type U32Graph = AdjListGraph<(), u32>;
+
+#[test]
+fn monotone_test()
+{
+	let samples = [U32(0), U32(2), U32(4), U32(5)];
+	assert_monotone::<CoarseSignAnalysis, U32Graph, _>(&[0], &sign_samples());
+	assert!(check_monotone::<WrappingAnalysis, U32Graph, _>(&[0], &samples).is_empty());
+	
+	let counterexamples = check_monotone::<WrappingAnalysis, U32Graph, _>(&[1], &samples);
+	assert!(!counterexamples.is_empty());
+	for c in counterexamples {
+		assert_eq!(c.property, "monotonicity");
+		assert!(c.dependencies.0 <= c.dependencies.1);
+		assert!(!c.results.0.le(&c.results.1));
+	}
+	assert!(check_monotone::<WrappingAnalysis, U32Graph, _>(&[1], &samples).iter()
+		.any(|c| c.dependencies == (&U32(4), &U32(5)) && c.results == (U32(5), U32(0))));
+}
+
+#[test]
+#[should_panic(expected = "monotonicity violated by action 3")]
+fn assert_monotone_test()
+{
+	assert_monotone::<WrappingAnalysis, U32Graph, _>(&[3], &[U32(0), U32(3)]);
+}
+
+#[test]
+fn distributive_test()
+{
+	assert_distributive::<WrappingAnalysis, U32Graph, _>(&[0], &[U32(0), U32(2), U32(5)]);
+	
+	let plus = SignPowerSet::singleton(Sign::Plus);
+	let minus = SignPowerSet::singleton(Sign::Minus);
+	let samples = [plus.clone(), minus.clone()];
+	let counterexamples = check_distributive::<CoarseSignAnalysis, U32Graph, _>(&[0], &samples);
+	assert!(counterexamples.iter().any(|c| c.dependencies == (&plus, &minus)
+		&& c.results == (SignPowerSet::top(), plus.clone() + &minus)));
+}
+
+#[test]
+#[should_panic(expected = "distributivity violated")]
+fn assert_distributive_test()
+{
+	assert_distributive::<CoarseSignAnalysis, U32Graph, _>(&[0], &sign_samples());
+}
+
+/// Integers around zero, including some that overflow when combined.
+fn concrete_samples() -> Vec<i64>
+{
+	vec![i64::MIN, -7, -3, -2, -1, 0, 1, 2, 3, 7, i64::MAX]
+}
+
+fn interval_samples() -> Vec<Interval>
+{
+	vec![
+		Interval::bottom(), Interval::constant(0), Interval::constant(2), Interval::new(-3, -1),
+		Interval::new(-2, 3), Interval::at_least(1), Interval::at_most(0), Interval::top(),
+	]
+}
+
+#[test]
+fn abstraction_test()
+{
+	let values = concrete_samples();
+	assert_abstraction::<Interval, _>(&values);
+	assert_abstraction::<SignPowerSet, _>(&values);
+	assert_abstraction::<Flat<i64>, _>(&values);
+	assert_abstraction::<Max<i64>, _>(&values);
+	assert_abstraction::<Min<i64>, _>(&values);
+	
+	assert_eq!(Interval::alpha_all(&[3, -2, 1]), Interval::new(-2, 3));
+	assert_eq!(SignPowerSet::alpha_all(&[3, 1]), SignPowerSet::singleton(Sign::Plus));
+	assert_eq!(Flat::alpha_all(&[3, 1]), Flat::Top);
+	assert!(Interval::alpha_all(&[]).is_bottom());
+	assert!(Max(3).gamma_contains(&-5) && !Max(3).gamma_contains(&4));
+	assert!(Min(3).gamma_contains(&4) && !Min(3).gamma_contains(&-5));
+	assert!(!Flat::<i64>::bottom().gamma_contains(&0));
+}
+
+#[test]
+fn sound_arithmetic_test()
+{
+	let values = concrete_samples();
+	let signs = sign_samples();
+	let intervals = interval_samples();
+	
+	assert_sound(&signs, &values, |s| s.negate(), |c| c.checked_neg());
+	assert_sound_binary(&signs, &values, |s1, s2| s1.plus(s2), |c1, c2| c1.checked_add(*c2));
+	assert_sound_binary(&signs, &values, |s1, s2| s1.minus(s2), |c1, c2| c1.checked_sub(*c2));
+	assert_sound_binary(&signs, &values, |s1, s2| s1.times(s2), |c1, c2| c1.checked_mul(*c2));
+	assert_sound_binary(&signs, &values, |s1, s2| s1.divide(s2), |c1, c2| c1.checked_div(*c2));
+	
+	assert_sound(&intervals, &values, |i| i.negate(), |c| c.checked_neg());
+	assert_sound_binary(&intervals, &values, |i1, i2| i1.plus(i2), |c1, c2| c1.checked_add(*c2));
+	assert_sound_binary(&intervals, &values, |i1, i2| i1.minus(i2), |c1, c2| c1.checked_sub(*c2));
+	assert_sound_binary(&intervals, &values, |i1, i2| i1.times(i2), |c1, c2| c1.checked_mul(*c2));
+	assert_sound_binary(&intervals, &values, |i1, i2| i1.divide(i2), |c1, c2| c1.checked_div(*c2));
+}
+
+#[test]
+fn unsound_test()
+{
+	// Forgets that negating zero gives zero
+	let negate = |s: &SignPowerSet| SignPowerSet::from_iter(s.iter()
+		.filter(|s| **s != Sign::Zero)
+		.map(|s| if *s == Sign::Plus { Sign::Minus } else { Sign::Plus }));
+	let zero = SignPowerSet::singleton(Sign::Zero);
+	
+	let unsoundnesses = check_sound(&sign_samples(), &concrete_samples(), negate, |c| c.checked_neg());
+	assert!(!unsoundnesses.is_empty());
+	assert!(unsoundnesses.contains(&Unsoundness{
+		abstract_values: vec![zero.clone(), SignPowerSet::bottom()],
+		concrete_values: vec![0, 0],
+	}));
+	for u in unsoundnesses.iter() {
+		assert!(u.abstract_values[0].gamma_contains(&u.concrete_values[0]));
+		assert!(!u.abstract_values[1].gamma_contains(&u.concrete_values[1]));
+	}
+	
+	// Undefined concrete results are ignored
+	assert!(check_sound(&[zero], &concrete_samples(), negate, |_| None).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "soundness violated by concrete values [1, 2]")]
+fn assert_sound_test()
+{
+	assert_sound(&[Interval::constant(1)], &[1], |i| *i, |c| Some(c + 1));
+}